@@ -17,15 +17,19 @@ pub fn build_program(node: &CNode) -> AProgram {
 pub fn build_expr(node: &CNode) -> Box<AExpr> {
     let child = node.at(0);
     match child.element {
-        &elements::productions::STRUCTURE  => build_structure(child),
-        &elements::expressions::LET        => build_var(child),
-        &elements::expressions::LITERAL    => build_literal(child),
-        &elements::expressions::CHAIN      => build_chain(child),
-        &elements::expressions::SEQUENCE   => build_sequence(child),
-        &elements::expressions::PREOP      => build_preop(child),
-        &elements::expressions::BINOP      => build_binop(child),
-        &elements::expressions::JUMP       => build_jump(child),
-        &elements::expressions::ASSIGNMENT => build_assign(child),
+        &elements::productions::STRUCTURE     => build_structure(child),
+        &elements::expressions::LET           => build_var(child),
+        &elements::expressions::LITERAL       => build_literal(child),
+        &elements::expressions::INTERPOLATION => build_interpolation(child),
+        &elements::expressions::CHAIN         => build_chain(child),
+        &elements::expressions::SEQUENCE      => build_sequence(child),
+        &elements::expressions::PREOP         => build_preop(child),
+        &elements::expressions::BINOP         => build_binop(child),
+        &elements::expressions::JUMP          => build_jump(child),
+        &elements::expressions::THROW         => build_throw(child),
+        &elements::expressions::ASSIGNMENT    => build_assign(child),
+        &elements::expressions::ANONYMOUS     => build_anonymous(child),
+        &elements::expressions::LAMBDA        => build_lambda(child),
         _ => panic!("{}", node.element.name),
     }
 }
@@ -38,6 +42,7 @@ fn build_structure(node: &CNode) -> Box<AExpr> {
         &elements::structures::LOOP  => AExpr::Loop(build_loop(child)),
         &elements::structures::WHILE => AExpr::While(build_while(child)),
         &elements::structures::FOR   => AExpr::For(build_for(child)),
+        &elements::structures::TRY   => AExpr::Try(build_try(child)),
         _ => panic!(),
     })
 }
@@ -51,6 +56,8 @@ fn build_stmts(node: &CNode) -> Box<[AStmt]> {
 fn build_stmt(node: &CNode) -> AStmt {
     let child = node.at(0);
     match child.element {
+        &elements::definitions::IMPORT     => AStmt::Import(build_import(child)),
+        &elements::definitions::EXPORT     => AStmt::Export(build_export(child)),
         &elements::productions::DEFINITION => AStmt::Def(build_def(child)),
         &elements::productions::STRUCTURE  => AStmt::Expr(build_structure(child)),
         &elements::productions::EXPRESSION => AStmt::Expr(build_expr(child)),
@@ -58,6 +65,53 @@ fn build_stmt(node: &CNode) -> AStmt {
     }
 }
 
+fn build_import(node: &CNode) -> AImport {
+    if node.at(1).element == &elements::productions::NAMES {
+        AImport {
+            pos: pos(node),
+            path: node.at(3).text(),
+            alias: None,
+            names: build_names(node.at(1)),
+        }
+    } else {
+        AImport {
+            pos: pos(node),
+            path: node.at(1).text(),
+            alias: Some(node.at(3).text()),
+            names: Box::new([]),
+        }
+    }
+}
+
+fn build_export(node: &CNode) -> AExport {
+    if node.at(1).element == &elements::productions::NAMES {
+        AExport {
+            pos: pos(node),
+            def: None,
+            names: build_names(node.at(1)),
+        }
+    } else {
+        let def = build_def(node.at(1));
+        let name = match &def {
+            ADef::Class(class) => class.name.clone(),
+            ADef::Function(function) => function.name.clone(),
+        };
+
+        AExport {
+            pos: pos(node),
+            def: Some(def),
+            names: Box::new([name]),
+        }
+    }
+}
+
+fn build_names(node: &CNode) -> Box<[Box<str>]> {
+    node.children()[1 .. node.children().len() - 1].iter()
+        .step_by(2)
+        .map(|child| child.text())
+        .collect()
+}
+
 fn build_def(node: &CNode) -> ADef {
     let child = node.at(0);
     match child.element {
@@ -73,12 +127,30 @@ fn build_class(node: &CNode) -> AClass {
         name: node.at(1).text(),
         generics: build_generics(node.at(2)),
         parent: build_option_type(node.at(3)),
-        methods: node.at(5).children().iter()
-            .map(|child| build_function(child))
+        members: node.at(5).children().iter()
+            .map(build_member)
             .collect(),
     }
 }
 
+fn build_member(node: &CNode) -> AMember {
+    match *node.element {
+        elements::definitions::ATTRIBUTE => AMember::Attribute(build_attribute(node)),
+        elements::definitions::FUNCTION  => AMember::Method(build_function(node)),
+        _ => panic!(),
+    }
+}
+
+fn build_attribute(node: &CNode) -> AAttribute {
+    let declaration = node.at(1);
+    AAttribute {
+        pos: pos(node),
+        name: declaration.at(0).text(),
+        r#type: build_option_type(declaration.at(1)),
+        default: node.at(2).children().get(1).map(build_expr),
+    }
+}
+
 fn build_generics(node: &CNode) -> Box<[AGeneric]> {
     node.children().get(1).iter().flat_map(|child| child.children().iter()
         .step_by(2)
@@ -105,6 +177,43 @@ fn build_function(node: &CNode) -> AFunction {
     }
 }
 
+fn build_anonymous(node: &CNode) -> Box<AExpr> {
+    Box::new(AExpr::Function(AFunction {
+        pos: pos(node),
+        name: Box::from("{anonymous}"),
+        generics: Box::new([]),
+        params: build_params(node.at(1)),
+        rest: build_rest(node.at(1)),
+        ret: build_option_type(node.at(2)),
+        body: build_block(node.at(3)),
+    }))
+}
+
+fn build_lambda(node: &CNode) -> Box<AExpr> {
+    let parameters = node.at(0);
+    let (params, rest) = match parameters.element {
+        &elements::productions::PARAMETERS => (build_params(parameters), build_rest(parameters)),
+        _ => (Box::from([AParameter { name: parameters.text(), r#type: None }]), None),
+    };
+
+    let expr = build_expr(node.at(2));
+    let body = ABlock {
+        pos: expr.pos(),
+        stmts: Box::new([]),
+        expr: Some(Box::new(AExpr::Return(AExprReturn { pos: expr.pos(), expr: Some(expr) }))),
+    };
+
+    Box::new(AExpr::Function(AFunction {
+        pos: pos(node),
+        name: Box::from("{anonymous}"),
+        generics: Box::new([]),
+        params,
+        rest,
+        ret: None,
+        body,
+    }))
+}
+
 fn build_params(node: &CNode) -> Box<[AParameter]> {
     node.at(1).children().iter()
         .step_by(2)
@@ -168,6 +277,28 @@ fn build_for(node: &CNode) -> AFor {
     }
 }
 
+fn build_try(node: &CNode) -> ATry {
+    let children = &node.children()[2 ..];
+    ATry {
+        pos: pos(node),
+        body: build_block(node.at(1)),
+        catch: children.iter()
+            .find(|child| child.element == &elements::structures::CATCH)
+            .map(build_catch),
+        finally: children.iter()
+            .find(|child| child.element == &elements::structures::FINALLY)
+            .map(|child| build_block(child.at(1))),
+    }
+}
+
+fn build_catch(node: &CNode) -> ACatch {
+    ACatch {
+        pos: pos(node),
+        name: node.at(1).text(),
+        body: build_block(node.at(2)),
+    }
+}
+
 fn build_var(node: &CNode) -> Box<AExpr> {
     Box::new(AExpr::Var(AExprVar {
         pos: pos(node),
@@ -191,6 +322,21 @@ fn build_literal(node: &CNode) -> Box<AExpr> {
     })
 }
 
+fn build_interpolation(node: &CNode) -> Box<AExpr> {
+    Box::new(AExpr::Interpolation(AExprInterpolation {
+        pos: pos(node),
+        literals: node.children().iter()
+            .step_by(2)
+            .map(|child| child.text())
+            .collect(),
+        exprs: node.children().iter()
+            .skip(1)
+            .step_by(2)
+            .map(build_expr)
+            .collect(),
+    }))
+}
+
 fn build_chain(node: &CNode) -> Box<AExpr> {
     Box::new(AExpr::Chain(AExprChain {
         pos: pos(node),
@@ -265,8 +411,17 @@ fn build_jump(node: &CNode) -> Box<AExpr> {
     })
 }
 
+fn build_throw(node: &CNode) -> Box<AExpr> {
+    Box::new(AExpr::Throw(AExprThrow {
+        pos: pos(node),
+        expr: build_expr(node.at(1)),
+    }))
+}
+
 fn build_assign(node: &CNode) -> Box<AExpr> {
+    let op = node.at(1).text();
+    let op = op.strip_suffix('=').filter(|op| !op.is_empty()).map(Box::from);
     let left = build_expr(node.at(0));
     let right = build_expr(node.at(2));
-    Box::new(AExpr::Assign(AExprAssign { pos: pos(node), left, right }))
+    Box::new(AExpr::Assign(AExprAssign { pos: pos(node), op, left, right }))
 }
@@ -20,6 +20,18 @@ impl Pos {
         length: 0,
     };
 
+    pub fn new(source: Option<Ref<Code>>, start: usize, length: usize) -> Self {
+        Self { source, start, length }
+    }
+
+    pub fn line(&self) -> usize {
+        self.start_y() + 1
+    }
+
+    pub fn column(&self) -> usize {
+        self.start_x() + 1
+    }
+
     pub fn print_name(&self) -> String {
         if let Some(source) = self.source {
             source.name.as_ref()
@@ -31,7 +43,7 @@ impl Pos {
     }
 
     pub fn print_pos(&self) -> String {
-        format!("{}:{}", self.start_y() + 1, self.start_x() + 1)
+        format!("{}:{}", self.line(), self.column())
     }
 
     pub fn print_node(&self) -> String {
@@ -40,9 +52,13 @@ impl Pos {
             string += "|\n";
             string += "| ";
             string += self.code_line();
+            if !string.ends_with('\n') {
+                string += "\n";
+            }
+
             string += "| ";
-            string += &" ".repeat(self.start - self.start_line_left());
-            string += &"^".repeat(min(self.length, self.start_line_right() - self.start));
+            string += &" ".repeat(self.start_x());
+            string += &"^".repeat(self.code_node().chars().count().max(1));
             string += "\n";
         }
 
@@ -54,20 +70,7 @@ impl Pos {
             return 0;
         };
 
-        let mut line = 0;
-        for (i, char) in source.text.chars().enumerate() {
-            if self.start == i {
-                break;
-            }
-
-            if char == '\n' {
-                line = 0;
-            } else {
-                line += 1;
-            }
-        }
-
-        line
+        source.text[self.start_line_left() .. self.start].chars().count()
     }
 
     fn start_y(&self) -> usize {
@@ -75,18 +78,7 @@ impl Pos {
             return 0;
         };
 
-        let mut col = 0;
-        for (i, char) in source.text.chars().enumerate() {
-            if self.start == i {
-                break;
-            }
-
-            if char == '\n' {
-                col += 1;
-            }
-        }
-
-        col
+        source.text[.. self.start].matches('\n').count()
     }
 
     fn start_line_left(&self) -> usize {
@@ -94,18 +86,7 @@ impl Pos {
             return 0;
         };
 
-        let mut count = 0;
-        for (i, char) in source.text.chars().enumerate() {
-            if i == self.start {
-                break;
-            }
-
-            if char == '\n' {
-                count = i + 1;
-            }
-        }
-
-        count
+        source.text[.. self.start].rfind('\n').map_or(0, |i| i + 1)
     }
 
     fn start_line_right(&self) -> usize {
@@ -113,18 +94,14 @@ impl Pos {
             return 0;
         };
 
-        let mut count = 0;
-        for (i, char) in source.text.chars().enumerate() {
-            count += 1;
-            if self.start < i && char == '\n' {
-                break;
-            }
-        }
-
-        count
+        source.text[self.start ..].find('\n').map_or(source.text.len(), |i| self.start + i + 1)
     }
 
     fn code_line(&self) -> &str {
         &self.source.as_ref().unwrap().text[self.start_line_left() .. self.start_line_right()]
     }
+
+    fn code_node(&self) -> &str {
+        &self.source.as_ref().unwrap().text[self.start .. min(self.start + self.length, self.start_line_right())]
+    }
 }
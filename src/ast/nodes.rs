@@ -14,7 +14,7 @@ pub struct AClass {
     pub name: Box<str>,
     pub parent: Option<Box<AExpr>>,
     pub generics: Box<[AGeneric]>,
-    pub methods: Box<[AFunction]>,
+    pub members: Box<[AMember]>,
 }
 
 pub struct AFunction {
@@ -38,8 +38,10 @@ pub enum AMember {
 }
 
 pub struct AAttribute {
-    name: Box<str>,
-    r#type: Option<AExpr>,
+    pub pos: Pos,
+    pub name: Box<str>,
+    pub r#type: Option<Box<AExpr>>,
+    pub default: Option<Box<AExpr>>,
 }
 
 pub struct AParameter {
@@ -50,6 +52,21 @@ pub struct AParameter {
 pub enum AStmt {
     Expr(Box<AExpr>),
     Def(ADef),
+    Import(AImport),
+    Export(AExport),
+}
+
+pub struct AImport {
+    pub pos: Pos,
+    pub path: Box<str>,
+    pub alias: Option<Box<str>>,
+    pub names: Box<[Box<str>]>,
+}
+
+pub struct AExport {
+    pub pos: Pos,
+    pub def: Option<ADef>,
+    pub names: Box<[Box<str>]>,
 }
 
 pub struct ABlock {
@@ -83,12 +100,26 @@ pub struct AFor {
     pub body: ABlock,
 }
 
+pub struct ATry {
+    pub pos: Pos,
+    pub body: ABlock,
+    pub catch: Option<ACatch>,
+    pub finally: Option<ABlock>,
+}
+
+pub struct ACatch {
+    pub pos: Pos,
+    pub name: Box<str>,
+    pub body: ABlock,
+}
+
 pub enum AExpr {
     Void(AExprVoid),
     Bool(AExprBool),
     Int(AExprInt),
     Float(AExprFloat),
     String(AExprString),
+    Interpolation(AExprInterpolation),
     Ident(AExprIdent),
     Var(AExprVar),
     Chain(AExprChain),
@@ -101,12 +132,15 @@ pub enum AExpr {
     Continue(AExprContinue),
     Break(AExprBreak),
     Return(AExprReturn),
+    Throw(AExprThrow),
     Block(ABlock),
     If(AIf),
     Loop(ALoop),
     While(AWhile),
     For(AFor),
+    Try(ATry),
     Assign(AExprAssign),
+    Function(AFunction),
 }
 
 pub struct AExprVoid {
@@ -133,6 +167,12 @@ pub struct AExprString {
     pub literal: Box<str>,
 }
 
+pub struct AExprInterpolation {
+    pub pos: Pos,
+    pub literals: Box<[Box<str>]>,
+    pub exprs: Box<[Box<AExpr>]>,
+}
+
 pub struct AExprIdent {
     pub pos: Pos,
     pub ident: Box<str>,
@@ -202,8 +242,14 @@ pub struct AExprReturn {
     pub expr: Option<Box<AExpr>>,
 }
 
+pub struct AExprThrow {
+    pub pos: Pos,
+    pub expr: Box<AExpr>,
+}
+
 pub struct AExprAssign {
     pub pos: Pos,
+    pub op: Option<Box<str>>,
     pub left: Box<AExpr>,
     pub right: Box<AExpr>,
 }
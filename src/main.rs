@@ -2,7 +2,6 @@
 #![allow(dead_code)]
 #![feature(box_into_inner)]
 #![feature(const_trait_impl)]
-#![feature(drain_filter)]
 #![feature(new_uninit)]
 #![feature(ptr_metadata)]
 #![feature(unsize)]
@@ -11,23 +10,60 @@
 mod ast;
 mod memory;
 mod parser;
+mod repl;
 mod runtime;
 
 #[cfg(test)]
 mod tests;
 
-use parser::Code;
-use runtime::engine::{Engine, Io};
+use runtime::engine::{Backend, Engine, Io};
 
-use std::env::args;
+use std::env::{ args, split_paths, var_os };
 use std::fs::read_to_string;
 use std::io::{ stderr, stdin, stdout };
+use std::path::PathBuf;
+use std::process::exit;
 
 fn main() {
-    let args: Vec<String> = args().collect();
-    if args.len() != 2 {
-        eprintln!("Incorrect arguments length.");
-        return;
+    let mut backend = Backend::Ast;
+    let mut incremental = false;
+    let mut verify = false;
+    let mut paths = Vec::new();
+    let mut file = None;
+    let mut args = args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "-I" {
+            let Some(path) = args.next() else {
+                eprintln!("Missing directory after `-I`.");
+                return;
+            };
+
+            paths.push(PathBuf::from(path));
+        } else if let Some(name) = arg.strip_prefix("--backend=") {
+            backend = match name {
+                "ast" => Backend::Ast,
+                "vm"  => Backend::Vm,
+                _ => {
+                    eprintln!("Unknown backend `{}`.", name);
+                    return;
+                },
+            };
+        } else if arg == "--gc-incremental" {
+            incremental = true;
+        } else if arg == "--gc-verify" {
+            verify = true;
+        } else if let Some(path) = arg.strip_prefix("-I") {
+            paths.push(PathBuf::from(path));
+        } else if file.is_none() {
+            file = Some(arg);
+        } else {
+            eprintln!("Incorrect arguments length.");
+            return;
+        }
+    }
+
+    if let Some(variable) = var_os("LIF_PATH") {
+        paths.extend(split_paths(&variable));
     }
 
     let grammar = parser::grammar();
@@ -36,7 +72,26 @@ fn main() {
     let mut error  = stderr();
     let io = Io::new(&mut input, &mut output, &mut error);
     let mut engine = Engine::new(io, &grammar);
-    let text = read_to_string(&args[1]).unwrap();
-    let code = Code::new(engine.grammar, engine.grammar.program, Some(&args[1]), text.into_boxed_str());
+    engine.backend = backend;
+    engine.gc.incremental = incremental;
+    engine.gc.verify = verify;
+    engine.paths = paths;
+    let Some(file) = file else {
+        repl::run(&mut engine, &mut stdin().lock());
+        return;
+    };
+
+    let text = match read_to_string(&file) {
+        Ok(text) => text,
+        Err(error) => {
+            writeln!(engine.io.err, "Cannot read `{}`: {}.", file, error).unwrap();
+            exit(1);
+        },
+    };
+
+    let Some(code) = engine.parse(Some(&file), text.into_boxed_str()) else {
+        exit(1);
+    };
+
     engine.run(code);
 }
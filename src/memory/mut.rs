@@ -1,3 +1,4 @@
+use std::hash::{ Hash, Hasher };
 use std::ops::{ Deref, DerefMut };
 use std::ptr::null_mut;
 
@@ -47,6 +48,12 @@ impl<T: ?Sized> PartialEq for Mut<T> {
 
 impl<T: ?Sized> Eq for Mut<T> {}
 
+impl<T: ?Sized> Hash for Mut<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.pointer.hash(state);
+    }
+}
+
 impl<T: ?Sized> Clone for Mut<T> {
     fn clone(&self) -> Self {
         Self::new(self.pointer)
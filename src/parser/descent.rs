@@ -214,7 +214,6 @@ impl DescentToken {
 
 impl Descent for DescentToken {
     fn descent(&self, parse: &mut Parse) -> Option<Vec<CNode>> {
-        let token = parse.next()?;
-        (token.element == self.element).then(|| vec![token])
+        parse.token(self.element).map(|token| vec![token])
     }
 }
@@ -1,4 +1,7 @@
 use crate::parser::Element;
 
+declare_node!(ATTRIBUTE);
 declare_node!(CLASS);
+declare_node!(EXPORT);
 declare_node!(FUNCTION);
+declare_node!(IMPORT);
@@ -1,10 +1,14 @@
 use crate::parser::Element;
 
+declare_node!(ANONYMOUS);
 declare_node!(ASSIGNMENT);
 declare_node!(BINOP);
 declare_node!(CHAIN);
+declare_node!(INTERPOLATION);
 declare_node!(JUMP);
+declare_node!(LAMBDA);
 declare_node!(LET);
 declare_node!(LITERAL);
 declare_node!(PREOP);
 declare_node!(SEQUENCE);
+declare_node!(THROW);
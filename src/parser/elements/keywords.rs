@@ -1,17 +1,25 @@
 use crate::parser::Element;
 
+declare_node!(AS);
 declare_node!(BREAK);
+declare_node!(CATCH);
 declare_node!(CLASS);
 declare_node!(CONTINUE);
 declare_node!(ELSE);
+declare_node!(EXPORT);
 declare_node!(FALSE);
+declare_node!(FINALLY);
 declare_node!(FOR);
+declare_node!(FROM);
 declare_node!(FUNCTION);
 declare_node!(IF);
+declare_node!(IMPORT);
 declare_node!(IN);
 declare_node!(LET);
 declare_node!(LOOP);
 declare_node!(RETURN);
+declare_node!(THROW);
 declare_node!(TRUE);
+declare_node!(TRY);
 declare_node!(VOID);
 declare_node!(WHILE);
@@ -3,4 +3,7 @@ use crate::parser::Element;
 declare_node!(INTEGER);
 declare_node!(FLOAT);
 declare_node!(STRING);
+declare_node!(STRING_START);
+declare_node!(STRING_MIDDLE);
+declare_node!(STRING_END);
 declare_node!(IDENTIFIER);
@@ -14,4 +14,6 @@ declare_node!(REST);
 declare_node!(GENERICS);
 declare_node!(GENERICS_LIST);
 declare_node!(TYPE);
-declare_node!(METHODS);
+declare_node!(DEFAULT);
+declare_node!(MEMBERS);
+declare_node!(NAMES);
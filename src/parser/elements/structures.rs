@@ -5,3 +5,6 @@ declare_node!(IF);
 declare_node!(LOOP);
 declare_node!(WHILE);
 declare_node!(FOR);
+declare_node!(TRY);
+declare_node!(CATCH);
+declare_node!(FINALLY);
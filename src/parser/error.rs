@@ -0,0 +1,72 @@
+use crate::ast::Pos;
+use crate::memory::Own;
+use crate::parser::{ Code, Element };
+
+pub struct ParseError {
+    pub pos: Pos,
+    pub token: Option<Box<str>>,
+    pub expected: Box<[&'static Element]>,
+    pub message: Option<Box<str>>,
+    code: Option<Own<Code>>,
+}
+
+impl ParseError {
+    pub fn new(pos: Pos, token: Option<Box<str>>, expected: Box<[&'static Element]>) -> Self {
+        Self { pos, token, expected, message: None, code: None }
+    }
+
+    pub fn new_lexer(pos: Pos, message: String) -> Self {
+        Self { pos, token: None, expected: Box::new([]), message: Some(Box::from(message)), code: None }
+    }
+
+    pub fn with_code(mut self, code: Own<Code>) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn take_code(&mut self) -> Option<Own<Code>> {
+        self.code.take()
+    }
+
+    pub fn name(&self) -> String {
+        self.pos.print_name()
+    }
+
+    pub fn line(&self) -> usize {
+        self.pos.line()
+    }
+
+    pub fn column(&self) -> usize {
+        self.pos.column()
+    }
+
+    pub fn get_message(&self) -> Box<str> {
+        format!("PARSING ERROR: {}\n--> `{}` {}\n{}",
+            self.description(),
+            self.pos.print_name(),
+            self.pos.print_pos(),
+            self.pos.print_node(),
+        ).into_boxed_str()
+    }
+
+    pub fn description(&self) -> String {
+        if let Some(message) = &self.message {
+            return message.to_string();
+        }
+
+        let token = match &self.token {
+            Some(token) => format!("unexpected token `{}`", token),
+            None => String::from("unexpected end of input"),
+        };
+
+        let expected = self.expected.iter()
+            .map(|element| format!("`{}`", element.name))
+            .collect::<Vec<_>>();
+
+        match expected.len() {
+            0 => token,
+            1 => format!("{}, expected {}", token, expected[0]),
+            _ => format!("{}, expected one of {}", token, expected.join(", ")),
+        }
+    }
+}
@@ -1,7 +1,7 @@
 #![allow(unused_variables)]
 
 use crate::memory::Ref;
-use crate::parser::{ Code, CNode, Parse };
+use crate::parser::{ Code, CNode, Parse, ParseError };
 use crate::parser::arena::{ Arena, ArenaRef };
 use crate::parser::elements;
 use crate::parser::ascent::*;
@@ -25,8 +25,8 @@ impl Grammar {
         }
     }
 
-    pub fn parse(&self, production: ArenaRef<dyn Descent>, code: Ref<Code>) -> Option<CNode> {
-        let tokens = lex(code);
+    pub fn parse(&self, production: ArenaRef<dyn Descent>, code: Ref<Code>) -> Result<CNode, ParseError> {
+        let tokens = lex(code)?;
         let mut parse = Parse::new(self, code, &tokens);
         parse.parse(production)
     }
@@ -105,23 +105,34 @@ pub fn get() -> Grammar {
         ( $element:expr ) => { ascents.define(AscentElement::new($element)) }
     }
 
+    let keyword_as              = descent_token!(&elements::keywords::AS);
     let keyword_break           = descent_token!(&elements::keywords::BREAK);
+    let keyword_catch           = descent_token!(&elements::keywords::CATCH);
     let keyword_class           = descent_token!(&elements::keywords::CLASS);
     let keyword_continue        = descent_token!(&elements::keywords::CONTINUE);
     let keyword_else            = descent_token!(&elements::keywords::ELSE);
+    let keyword_export          = descent_token!(&elements::keywords::EXPORT);
     let keyword_false           = descent_token!(&elements::keywords::FALSE);
+    let keyword_finally         = descent_token!(&elements::keywords::FINALLY);
     let keyword_for             = descent_token!(&elements::keywords::FOR);
+    let keyword_from            = descent_token!(&elements::keywords::FROM);
     let keyword_function        = descent_token!(&elements::keywords::FUNCTION);
     let keyword_if              = descent_token!(&elements::keywords::IF);
+    let keyword_import          = descent_token!(&elements::keywords::IMPORT);
     let keyword_in              = descent_token!(&elements::keywords::IN);
     let keyword_let             = descent_token!(&elements::keywords::LET);
     let keyword_loop            = descent_token!(&elements::keywords::LOOP);
     let keyword_return          = descent_token!(&elements::keywords::RETURN);
+    let keyword_throw           = descent_token!(&elements::keywords::THROW);
     let keyword_true            = descent_token!(&elements::keywords::TRUE);
+    let keyword_try             = descent_token!(&elements::keywords::TRY);
     let keyword_while           = descent_token!(&elements::keywords::WHILE);
     let literal_integer        = descent_token!(&elements::literals::INTEGER);
     let literal_float          = descent_token!(&elements::literals::FLOAT);
     let literal_string         = descent_token!(&elements::literals::STRING);
+    let literal_string_start   = descent_token!(&elements::literals::STRING_START);
+    let literal_string_middle  = descent_token!(&elements::literals::STRING_MIDDLE);
+    let literal_string_end     = descent_token!(&elements::literals::STRING_END);
     let literal_identifier     = descent_token!(&elements::literals::IDENTIFIER);
     let symbol_plus             = descent_token!(&elements::symbols::PLUS);
     let symbol_plus_eq          = descent_token!(&elements::symbols::PLUS_EQ);
@@ -266,6 +277,18 @@ pub fn get() -> Grammar {
         &elements::expressions::LITERAL
     );
 
+    let interpolation = descent_element!(
+        descent_sequence![
+            literal_string_start,
+            expression,
+            descent_zero_or_more!(
+                descent_sequence![literal_string_middle, expression]
+            ),
+            literal_string_end,
+        ],
+        &elements::expressions::INTERPOLATION
+    );
+
     let declaration = descent_element!(
         descent_sequence![literal_identifier, r#type],
         &elements::productions::DECLARATION
@@ -284,6 +307,11 @@ pub fn get() -> Grammar {
         &elements::expressions::JUMP
     );
 
+    let throw = descent_element!(
+        descent_sequence![keyword_throw, expression],
+        &elements::expressions::THROW
+    );
+
     let block = descent_element!(
         descent_sequence![symbol_brace_l, statements, expression_option, symbol_brace_r],
         &elements::structures::BLOCK
@@ -316,8 +344,28 @@ pub fn get() -> Grammar {
         &elements::structures::FOR
     );
 
+    let r#try = descent_element!(
+        descent_sequence![
+            keyword_try,
+            block,
+            descent_option!(
+                descent_element!(
+                    descent_sequence![keyword_catch, literal_identifier, block],
+                    &elements::structures::CATCH
+                )
+            ),
+            descent_option!(
+                descent_element!(
+                    descent_sequence![keyword_finally, block],
+                    &elements::structures::FINALLY
+                )
+            ),
+        ],
+        &elements::structures::TRY
+    );
+
     let structure = descent_element!(
-        descent_choice![block, r#if, r#loop, r#while, r#for],
+        descent_choice![block, r#if, r#loop, r#while, r#for, r#try],
         &elements::productions::STRUCTURE
     );
 
@@ -379,13 +427,39 @@ pub fn get() -> Grammar {
         &elements::definitions::FUNCTION
     );
 
-    let methods = descent_element!(
-        descent_zero_or_more!(function),
-        &elements::productions::METHODS
+    let anonymous = descent_element!(
+        descent_sequence![keyword_function, parameters, r#type, block],
+        &elements::expressions::ANONYMOUS
+    );
+
+    let lambda = descent_element!(
+        descent_sequence![
+            descent_choice![literal_identifier, parameters],
+            symbol_arrow,
+            expression,
+        ],
+        &elements::expressions::LAMBDA
+    );
+
+    let default = descent_element!(
+        descent_option!(
+            descent_sequence![symbol_equal, expression]
+        ),
+        &elements::productions::DEFAULT
+    );
+
+    let attribute = descent_element!(
+        descent_sequence![keyword_let, declaration, default, symbol_semicolon],
+        &elements::definitions::ATTRIBUTE
+    );
+
+    let members = descent_element!(
+        descent_zero_or_more!(descent_choice![attribute, function]),
+        &elements::productions::MEMBERS
     );
 
     let class = descent_element!(
-        descent_sequence![keyword_class, literal_identifier, generics, r#type, symbol_brace_l, methods, symbol_brace_r],
+        descent_sequence![keyword_class, literal_identifier, generics, r#type, symbol_brace_l, members, symbol_brace_r],
         &elements::definitions::CLASS
     );
 
@@ -394,6 +468,38 @@ pub fn get() -> Grammar {
         &elements::productions::DEFINITION
     );
 
+    let names = descent_element!(
+        descent_sequence![
+            symbol_brace_l,
+            macro_list!(literal_identifier, symbol_comma),
+            symbol_brace_r,
+        ],
+        &elements::productions::NAMES
+    );
+
+    let import = descent_element!(
+        descent_sequence![
+            keyword_import,
+            descent_choice![
+                descent_sequence![literal_string, keyword_as, literal_identifier],
+                descent_sequence![names, keyword_from, literal_string],
+            ],
+            symbol_semicolon,
+        ],
+        &elements::definitions::IMPORT
+    );
+
+    let export = descent_element!(
+        descent_sequence![
+            keyword_export,
+            descent_choice![
+                definition,
+                descent_sequence![names, symbol_semicolon],
+            ],
+        ],
+        &elements::definitions::EXPORT
+    );
+
     let preop = descent_element!(
         descent_sequence![
             descent_choice![symbol_tilde, symbol_minus, symbol_plus, symbol_exclamation],
@@ -403,7 +509,7 @@ pub fn get() -> Grammar {
     );
 
     let expression_core = descent_element!(
-        descent_choice![structure, jump, r#let, literal, preop],
+        descent_choice![structure, jump, throw, r#let, anonymous, lambda, literal, interpolation, preop],
         &elements::productions::EXPRESSION
     );
 
@@ -446,7 +552,26 @@ pub fn get() -> Grammar {
         ]
     ));
 
-    let binop_1 = macro_binop!(expression_base, descent_choice![symbol_asterisk, symbol_slash, symbol_percent, symbol_asterisk_d]);
+    let binop_0 = descents.declare();
+    descents.swap(binop_0, descent_ascent!(
+        ascent_sequence![
+            ascent_descent!(expression_base),
+            ascent_option!(
+                ascent_sequence![
+                    ascent_descent!(
+                        descent_sequence![
+                            symbol_asterisk_d,
+                            binop_0,
+                        ]
+                    ),
+                    ascent_element!(&elements::expressions::BINOP),
+                    ascent_element!(&elements::productions::EXPRESSION),
+                ]
+            ),
+        ]
+    ));
+
+    let binop_1 = macro_binop!(binop_0, descent_choice![symbol_asterisk, symbol_slash, symbol_percent]);
 
     descents.swap(binop_base, descent_alias!(binop_1));
 
@@ -483,6 +608,8 @@ pub fn get() -> Grammar {
 
     let statement = descent_element!(
         descent_choice![
+            import,
+            export,
             descent_sequence![definition, no_semicolon],
             descent_sequence![structure, no_semicolon],
             descent_sequence![expression, symbol_semicolon],
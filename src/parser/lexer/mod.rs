@@ -1,16 +1,47 @@
 mod node;
 mod nodes;
 
+use crate::ast::Pos;
 use crate::memory::Ref;
-use crate::parser::{ Code, CNode, Element };
+use crate::parser::{ Code, CNode, Element, ParseError };
 use crate::parser::elements::ignores::{ WHITESPACE, ENDLINE, COMMENT_LINE, COMMENT_BLOCK };
+use crate::parser::elements::literals::{ STRING, STRING_START, STRING_MIDDLE, STRING_END };
+use crate::parser::elements::symbols::{ BRACE_L, BRACE_R };
 
-use nodes::ROOT;
+use node::Node;
+use nodes::{ ROOT, STRING_CONTENT };
 
-pub fn lex(code: Ref<Code>) -> Vec<CNode> {
+pub fn lex(code: Ref<Code>) -> Result<Vec<CNode>, ParseError> {
     let mut tokens = Vec::new();
+    let mut braces = Vec::new();
     let mut shift = 0;
-    while let Some((element, length)) = automaton(&code.text[shift ..]) {
+    while shift < code.text.len() {
+        let (element, length) = if braces.last() == Some(&0) && code.text[shift ..].starts_with('}') {
+            braces.pop();
+            match automaton(code, &STRING_CONTENT, shift, shift + 1)? {
+                (element, length) if element == &STRING => (&STRING_END, length + 1),
+                (_, length) => (&STRING_MIDDLE, length + 1),
+            }
+        } else {
+            automaton(code, &ROOT, shift, shift)?
+        };
+
+        if element == &STRING || element == &STRING_START || element == &STRING_MIDDLE || element == &STRING_END {
+            check_escapes(code, shift, shift + length)?;
+        }
+
+        if element == &STRING_START || element == &STRING_MIDDLE {
+            braces.push(0);
+        } else if element == &BRACE_L {
+            if let Some(depth) = braces.last_mut() {
+                *depth += 1;
+            }
+        } else if element == &BRACE_R {
+            if let Some(depth) = braces.last_mut() {
+                *depth -= 1;
+            }
+        }
+
         if element != &WHITESPACE && element != &ENDLINE && element != &COMMENT_LINE && element != &COMMENT_BLOCK {
             tokens.push(CNode::new_token(code, element, shift, shift + length));
         }
@@ -18,21 +49,73 @@ pub fn lex(code: Ref<Code>) -> Vec<CNode> {
         shift += length;
     }
 
-    tokens
+    Ok(tokens)
 }
 
-fn automaton(string: &str) -> Option<(&'static Element, usize)> {
-    let mut node = &ROOT;
-    let mut counter = 0;
-    for character in string.chars() {
+fn automaton(code: Ref<Code>, mut node: &'static Node, left: usize, start: usize) -> Result<(&'static Element, usize), ParseError> {
+    let mut right = start;
+    let mut accepted = None;
+    for character in code.text[start ..].chars() {
         let next = (node.execute)(character);
         if next.is_none() {
             break;
         }
 
+        if let Some(element) = node.element {
+            accepted = Some((element, right - start));
+        }
+
         node = next.unwrap();
-        counter += 1;
+        right += character.len_utf8();
+    }
+
+    match (node.element, accepted) {
+        (Some(element), _) => Ok((element, right - start)),
+        (None, Some(accepted)) if code.text[.. right].ends_with('.') && code.text[right ..].starts_with('.') => Ok(accepted),
+        (None, _) => Err(error(code, left, right)),
+    }
+}
+
+fn error(code: Ref<Code>, left: usize, right: usize) -> ParseError {
+    let text = &code.text[left .. right];
+    let next = code.text[right ..].chars().next();
+    let length = next.map_or(0, char::len_utf8);
+    if text.starts_with('"') || text.starts_with('}') {
+        if next.is_none() {
+            return ParseError::new_lexer(Pos::new(Some(code), left, 1), String::from("unterminated string"));
+        }
+
+        let escape = left + text.rfind('\\').unwrap();
+        let sequence = &code.text[escape .. right + length];
+        return ParseError::new_lexer(Pos::new(Some(code), escape, sequence.len()), format!("invalid escape sequence `{}`", sequence));
+    }
+
+    match next {
+        _ if !text.is_empty() => ParseError::new_lexer(Pos::new(Some(code), left, text.len()), format!("invalid token `{}`", text)),
+        Some(character) => ParseError::new_lexer(Pos::new(Some(code), right, length), format!("unexpected character `{}`", character)),
+        None => ParseError::new_lexer(Pos::new(Some(code), right, 1), String::from("unexpected end of input")),
+    }
+}
+
+fn check_escapes(code: Ref<Code>, left: usize, right: usize) -> Result<(), ParseError> {
+    let text = &code.text[left .. right];
+    let mut characters = text.char_indices();
+    while let Some((index, character)) = characters.next() {
+        if character != '\\' {
+            continue;
+        }
+
+        let Some((_, 'u')) = characters.next() else {
+            continue;
+        };
+
+        let end = index + text[index ..].find('}').unwrap() + 1;
+        let digits = &text[index + 3 .. end - 1];
+        if u32::from_str_radix(digits, 16).ok().and_then(char::from_u32).is_none() {
+            let sequence = &text[index .. end];
+            return Err(ParseError::new_lexer(Pos::new(Some(code), left + index, sequence.len()), format!("invalid unicode escape `{}`", sequence)));
+        }
     }
 
-    Some((node.element?, counter))
+    Ok(())
 }
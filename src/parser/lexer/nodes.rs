@@ -3,6 +3,7 @@ use crate::parser::lexer::node::Node;
 
 pub const ROOT: Node = Node::new_null(&|character| {
     Some(match character {
+        'a' => &KEYWORD_A,
         'b' => &KEYWORD_B,
         'c' => &KEYWORD_C,
         'e' => &KEYWORD_E,
@@ -49,6 +50,21 @@ pub const ROOT: Node = Node::new_null(&|character| {
     })
 });
 
+const KEYWORD_A: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        's' => &KEYWORD_AS,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_AS: Node = Node::new(&elements::keywords::AS, &|character| {
+    Some(match character {
+        'a' ..= 'z' | 'A' ..= 'Z' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
 const KEYWORD_B: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
     Some(match character {
         'r' => &KEYWORD_BR,
@@ -90,6 +106,7 @@ const KEYWORD_BREAK: Node = Node::new(&elements::keywords::BREAK, &|character| {
 
 const KEYWORD_C: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
     Some(match character {
+        'a' => &KEYWORD_CA,
         'l' => &KEYWORD_CL,
         'o' => &KEYWORD_CO,
         'a' ..= 'z' | 'A' ..= 'Z' | '_' => &IDENTIFIER,
@@ -97,6 +114,37 @@ const KEYWORD_C: Node = Node::new(&elements::literals::IDENTIFIER, &|character|
     })
 });
 
+const KEYWORD_CA: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        't' => &KEYWORD_CAT,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_CAT: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'c' => &KEYWORD_CATC,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_CATC: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'h' => &KEYWORD_CATCH,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_CATCH: Node = Node::new(&elements::keywords::CATCH, &|character| {
+    Some(match character {
+        'a' ..= 'z' | 'A' ..= 'Z' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
 const KEYWORD_CL: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
     Some(match character {
         'a' =>  &KEYWORD_CLA,
@@ -186,6 +234,7 @@ const KEYWORD_CONTINUE: Node = Node::new(&elements::keywords::CONTINUE, &|charac
 const KEYWORD_E: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
     Some(match character {
         'l' => &KEYWORD_EL,
+        'x' => &KEYWORD_EX,
         'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
         _ => return None,
     })
@@ -214,10 +263,51 @@ const KEYWORD_ELSE: Node = Node::new(&elements::keywords::ELSE, &|character| {
     })
 });
 
+const KEYWORD_EX: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'p' => &KEYWORD_EXP,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_EXP: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'o' => &KEYWORD_EXPO,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_EXPO: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'r' => &KEYWORD_EXPOR,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_EXPOR: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        't' => &KEYWORD_EXPORT,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_EXPORT: Node = Node::new(&elements::keywords::EXPORT, &|character| {
+    Some(match character {
+        'a' ..= 'z' | 'A' ..= 'Z' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
 const KEYWORD_F: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
     Some(match character {
         'a' => &KEYWORD_FA,
+        'i' => &KEYWORD_FI,
         'o' => &KEYWORD_FO,
+        'r' => &KEYWORD_FR,
         'u' => &KEYWORD_FU,
         'b' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
         _ => return None,
@@ -255,6 +345,53 @@ const KEYWORD_FALSE: Node = Node::new(&elements::keywords::FALSE, &|character| {
     })
 });
 
+const KEYWORD_FI: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'n' => &KEYWORD_FIN,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_FIN: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'a' => &KEYWORD_FINA,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_FINA: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'l' => &KEYWORD_FINAL,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_FINAL: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'l' => &KEYWORD_FINALL,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_FINALL: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'y' => &KEYWORD_FINALLY,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_FINALLY: Node = Node::new(&elements::keywords::FINALLY, &|character| {
+    Some(match character {
+        'a' ..= 'z' | 'A' ..= 'Z' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
 const KEYWORD_FO: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
     Some(match character {
         'r' => &KEYWORD_FOR,
@@ -270,6 +407,29 @@ const KEYWORD_FOR: Node = Node::new(&elements::keywords::FOR, &|character| {
     })
 });
 
+const KEYWORD_FR: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'o' => &KEYWORD_FRO,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_FRO: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'm' => &KEYWORD_FROM,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_FROM: Node = Node::new(&elements::keywords::FROM, &|character| {
+    Some(match character {
+        'a' ..= 'z' | 'A' ..= 'Z' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
 const KEYWORD_FU: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
     Some(match character {
         'n' => &KEYWORD_FUN,
@@ -328,6 +488,7 @@ const KEYWORD_FUNCTION: Node = Node::new(&elements::keywords::FUNCTION, &|charac
 const KEYWORD_I: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
     Some(match character {
         'f' => &KEYWORD_IF,
+        'm' => &KEYWORD_IM,
         'n' => &KEYWORD_IN,
         'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
         _ => return None,
@@ -341,6 +502,45 @@ const KEYWORD_IF: Node = Node::new(&elements::keywords::IF, &|character| {
     })
 });
 
+const KEYWORD_IM: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'p' => &KEYWORD_IMP,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_IMP: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'o' => &KEYWORD_IMPO,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_IMPO: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'r' => &KEYWORD_IMPOR,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_IMPOR: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        't' => &KEYWORD_IMPORT,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_IMPORT: Node = Node::new(&elements::keywords::IMPORT, &|character| {
+    Some(match character {
+        'a' ..= 'z' | 'A' ..= 'Z' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
 const KEYWORD_IN: Node = Node::new(&elements::keywords::IN, &|character| {
     Some(match character {
         'a' ..= 'z' | 'A' ..= 'Z' | '_' => &IDENTIFIER,
@@ -444,15 +644,48 @@ const KEYWORD_RETURN: Node = Node::new(&elements::keywords::RETURN, &|character|
 
 const KEYWORD_T: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
     Some(match character {
+        'h' => &KEYWORD_TH,
         'r' => &KEYWORD_TR,
         'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
         _ => return None,
     })
 });
 
+const KEYWORD_TH: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'r' => &KEYWORD_THR,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_THR: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'o' => &KEYWORD_THRO,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_THRO: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
+    Some(match character {
+        'w' => &KEYWORD_THROW,
+        'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
+const KEYWORD_THROW: Node = Node::new(&elements::keywords::THROW, &|character| {
+    Some(match character {
+        'a' ..= 'z' | 'A' ..= 'Z' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
 const KEYWORD_TR: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
     Some(match character {
         'u' => &KEYWORD_TRU,
+        'y' => &KEYWORD_TRY,
         'a' ..= 'z' | 'A' ..= 'Z' | '0' ..= '9' | '_' => &IDENTIFIER,
         _ => return None,
     })
@@ -473,6 +706,13 @@ const KEYWORD_TRUE: Node = Node::new(&elements::keywords::TRUE, &|character| {
     })
 });
 
+const KEYWORD_TRY: Node = Node::new(&elements::keywords::TRY, &|character| {
+    Some(match character {
+        'a' ..= 'z' | 'A' ..= 'Z' | '_' => &IDENTIFIER,
+        _ => return None,
+    })
+});
+
 const KEYWORD_W: Node = Node::new(&elements::literals::IDENTIFIER, &|character| {
     Some(match character {
         'h' => &KEYWORD_WH,
@@ -524,12 +764,15 @@ const SYMBOL_PLUS_EQ: Node = Node::new_final(&elements::symbols::PLUS_EQ);
 const SYMBOL_MINUS: Node = Node::new(&elements::symbols::MINUS, &|character| {
     Some(match character {
         '=' => &SYMBOL_MINUS_EQ,
+        '>' => &SYMBOL_ARROW,
         _ => return None,
     })
 });
 
 const SYMBOL_MINUS_EQ: Node = Node::new_final(&elements::symbols::MINUS_EQ);
 
+const SYMBOL_ARROW: Node = Node::new_final(&elements::symbols::ARROW);
+
 const SYMBOL_ASTERISK: Node = Node::new(&elements::symbols::ASTERISK, &|character| {
     Some(match character {
         '=' => &SYMBOL_ASTERISK_EQ,
@@ -590,15 +833,12 @@ const SYMBOL_EXCLAMATION_EQ: Node = Node::new_final(&elements::symbols::EXCLAMAT
 const SYMBOL_EQUAL: Node = Node::new(&elements::symbols::EQUAL, &|character| {
     Some(match character {
         '=' => &SYMBOL_EQUAL_D,
-        '>' => &SYMBOL_ARROW,
         _ => return None,
     })
 });
 
 const SYMBOL_EQUAL_D: Node = Node::new_final(&elements::symbols::EQUAL_D);
 
-const SYMBOL_ARROW: Node = Node::new_final(&elements::symbols::ARROW);
-
 const SYMBOL_PIPE: Node = Node::new(&elements::symbols::PIPE, &|character| {
     Some(match character {
         '=' => &SYMBOL_PIPE_EQ,
@@ -740,15 +980,42 @@ const SYMBOL_SEMICOLON: Node = Node::new_final(&elements::symbols::SEMICOLON);
 
 const SYMBOL_BACKSLASH: Node = Node::new_final(&elements::symbols::BACKSLASH);
 
-const STRING_CONTENT: Node = Node::new_null(&|character| {
+pub const STRING_CONTENT: Node = Node::new_null(&|character| {
     Some(match character {
         '"' => &STRING,
+        '{' => &STRING_START,
+        '\\' => &STRING_ESCAPE,
         _ => &STRING_CONTENT,
     })
 });
 
+const STRING_ESCAPE: Node = Node::new_null(&|character| {
+    Some(match character {
+        'n' | 'r' | 't' | '0' | '\\' | '"' | '\'' | '{' | '}' => &STRING_CONTENT,
+        'u' => &STRING_UNICODE_START,
+        _ => return None,
+    })
+});
+
+const STRING_UNICODE_START: Node = Node::new_null(&|character| {
+    Some(match character {
+        '{' => &STRING_UNICODE,
+        _ => return None,
+    })
+});
+
+const STRING_UNICODE: Node = Node::new_null(&|character| {
+    Some(match character {
+        '0' ..= '9' | 'a' ..= 'f' | 'A' ..= 'F' => &STRING_UNICODE,
+        '}' => &STRING_CONTENT,
+        _ => return None,
+    })
+});
+
 const STRING: Node = Node::new_final(&elements::literals::STRING);
 
+const STRING_START: Node = Node::new_final(&elements::literals::STRING_START);
+
 const NUMBER_BASE: Node = Node::new(&elements::literals::INTEGER, &|character| {
     Some(match character {
         'b' => &BINARY_START,
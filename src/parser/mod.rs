@@ -7,6 +7,7 @@ mod ascent;
 mod code;
 mod descent;
 mod element;
+mod error;
 mod grammar;
 mod lexer;
 mod printer;
@@ -17,6 +18,7 @@ pub use grammar::get as grammar;
 
 pub use code::{Ast, Code};
 pub use element::Element;
+pub use error::ParseError;
 pub use grammar::Grammar;
 pub use parse::Parse;
 pub use node::CNode;
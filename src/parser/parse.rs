@@ -1,17 +1,17 @@
+use crate::ast::Pos;
 use crate::memory::Ref;
-use crate::parser::{ Code, CNode, Grammar };
+use crate::parser::{ Code, CNode, Element, Grammar, ParseError };
 use crate::parser::arena::ArenaRef;
 use crate::parser::ascent::*;
 use crate::parser::descent::*;
 
-use std::cmp;
-
 pub struct Parse<'a> {
     grammar: &'a Grammar,
     pub code: Ref<Code>,
     tokens: &'a [CNode],
     cursor: usize,
     reach: usize,
+    expected: Vec<&'static Element>,
 }
 
 impl<'a> Parse<'a> {
@@ -22,6 +22,7 @@ impl<'a> Parse<'a> {
             tokens,
             cursor: 0,
             reach: 0,
+            expected: Vec::new(),
         }
     }
 
@@ -32,10 +33,6 @@ impl<'a> Parse<'a> {
     pub fn next(&mut self) -> Option<CNode> {
         let option = self.tokens.get(self.cursor);
         if let Some(token) = option {
-            if self.reach < self.cursor {
-                self.reach = self.cursor;
-            }
-
             self.cursor += 1;
             return Some(token.clone());
         }
@@ -43,6 +40,29 @@ impl<'a> Parse<'a> {
         None
     }
 
+    pub fn token(&mut self, element: &'static Element) -> Option<CNode> {
+        let cursor = self.cursor;
+        if let Some(token) = self.next() {
+            if token.element == element {
+                return Some(token);
+            }
+        }
+
+        self.expect(cursor, element);
+        None
+    }
+
+    fn expect(&mut self, cursor: usize, element: &'static Element) {
+        if cursor > self.reach {
+            self.reach = cursor;
+            self.expected.clear();
+        }
+
+        if cursor == self.reach && !self.expected.contains(&element) {
+            self.expected.push(element);
+        }
+    }
+
     fn run(&mut self, callback: impl FnOnce(&mut Self) -> Option<Vec<CNode>>) -> Option<Vec<CNode>>{
         let cursor = self.cursor;
         let nodes = callback(self);
@@ -76,28 +96,24 @@ impl<'a> Parse<'a> {
         self.run_predicate(|parse| parse.grammar.ascents.get(r#ref).ascent(parse, nodes))
     }
 
-    pub fn parse(&mut self, production: ArenaRef<dyn Descent>) -> Option<CNode> {
-        let node = if let Some(mut nodes) = self.grammar.descents.get(production).descent(self) {
-            nodes.pop()
+    pub fn parse(&mut self, production: ArenaRef<dyn Descent>) -> Result<CNode, ParseError> {
+        if let Some(mut nodes) = self.grammar.descents.get(production).descent(self) {
+            if self.done() {
+                return Ok(nodes.pop().unwrap());
+            }
+        }
+
+        Err(self.error())
+    }
+
+    fn error(&self) -> ParseError {
+        let (pos, token) = if let Some(token) = self.tokens.get(self.reach) {
+            (Pos::new(Some(self.code), token.left(), token.right() - token.left()), Some(token.text()))
         } else {
-            return None;
+            let end = self.tokens.last().map_or(0, |token| token.right());
+            (Pos::new(Some(self.code), end, 1), None)
         };
 
-        if self.done() {
-            node
-        } else {
-            let token = &self.tokens[self.reach];
-            println!("PARSING ERROR, LINE {}, POSITION {}, UNEXPECTED TOKEN: {:?} - {}\n\n{}\n{}{}",
-                self.code.node_y(token),
-                self.code.node_x(token),
-                self.code.node_str(token),
-                token.element.name,
-                self.code.node_line(token),
-                " ".repeat(self.code.node_shift_left(token)),
-                "^".repeat(cmp::min(self.code.node_str(token).len(), self.code.node_shift_right(token)))
-            );
-
-            None
-        }
+        ParseError::new(pos, token, self.expected.clone().into_boxed_slice())
     }
 }
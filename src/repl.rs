@@ -0,0 +1,65 @@
+use crate::parser::Code;
+use crate::runtime::engine::Engine;
+
+use std::io::BufRead;
+
+pub fn run(engine: &mut Engine, input: &mut dyn BufRead) {
+    loop {
+        let Some(text) = read_entry(engine, input) else {
+            return;
+        };
+
+        if text.trim().is_empty() {
+            continue;
+        }
+
+        let productions = [engine.grammar.program, engine.grammar.expression];
+        let code = match Code::parse(engine.grammar, &productions, None, text.into_boxed_str()) {
+            Ok(code) => code,
+            Err(error) => {
+                writeln!(engine.io.err, "{}", error.get_message()).unwrap();
+                continue;
+            },
+        };
+
+        if let Some(value) = engine.run_repl(code) {
+            if value.isa(engine.env.string) {
+                writeln!(engine.io.out, "{}", value.as_string().as_ref()).unwrap();
+            }
+        }
+    }
+}
+
+fn read_entry(engine: &mut Engine, input: &mut dyn BufRead) -> Option<String> {
+    let mut text = String::new();
+    loop {
+        write!(engine.io.out, "{}", if text.is_empty() { "> " } else { "... " }).unwrap();
+        engine.io.out.flush().unwrap();
+        if input.read_line(&mut text).unwrap() == 0 {
+            return (!text.is_empty()).then_some(text);
+        }
+
+        if depth(&text) <= 0 {
+            return Some(text);
+        }
+    }
+}
+
+fn depth(text: &str) -> isize {
+    let mut depth = 0;
+    let mut string = false;
+    let mut chars = text.chars();
+    while let Some(r#char) = chars.next() {
+        match r#char {
+            '"' => string = !string,
+            '\\' if string => {
+                chars.next();
+            },
+            '{' if !string => depth += 1,
+            '}' if !string => depth -= 1,
+            _ => (),
+        }
+    }
+
+    depth
+}
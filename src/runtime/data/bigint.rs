@@ -0,0 +1,418 @@
+use crate::runtime::gc::{GcRef, GcTrace};
+
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter, Result};
+use std::mem::size_of;
+
+pub type GcBigInt = GcRef<BigInt>;
+
+const BASE: u64 = 1 << 32;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    digits: Vec<u32>,
+}
+
+impl BigInt {
+    pub fn from_i64(value: i64) -> Self {
+        let magnitude = value.unsigned_abs();
+        Self::new(value < 0, vec![magnitude as u32, (magnitude >> 32) as u32])
+    }
+
+    pub fn parse(string: &str, radix: u32) -> Option<Self> {
+        let (negative, digits) = match string.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, string.strip_prefix('+').unwrap_or(string)),
+        };
+
+        if digits.is_empty() {
+            return None;
+        }
+
+        let mut magnitude = Vec::new();
+        for character in digits.chars() {
+            let digit = character.to_digit(radix)?;
+            magnitude = mul_small_add(&magnitude, radix, digit);
+        }
+
+        Some(Self::new(negative, magnitude))
+    }
+
+    fn new(negative: bool, mut digits: Vec<u32>) -> Self {
+        trim(&mut digits);
+        Self { negative: negative && !digits.is_empty(), digits }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.digits.len() > 2 {
+            return None;
+        }
+
+        let magnitude = self.digits.iter().rev().fold(0u64, |value, digit| (value << 32) | *digit as u64);
+        if self.negative {
+            0i64.checked_sub_unsigned(magnitude)
+        } else {
+            i64::try_from(magnitude).ok()
+        }
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self.digits.iter().rev().fold(0.0, |value, digit| value * BASE as f64 + *digit as f64);
+        if self.negative { -magnitude } else { magnitude }
+    }
+
+    pub fn neg(&self) -> Self {
+        Self::new(!self.negative, self.digits.clone())
+    }
+
+    pub fn abs(&self) -> Self {
+        Self::new(false, self.digits.clone())
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        if self.negative == other.negative {
+            return Self::new(self.negative, add(&self.digits, &other.digits));
+        }
+
+        match compare(&self.digits, &other.digits) {
+            Ordering::Less => Self::new(other.negative, sub(&other.digits, &self.digits)),
+            _              => Self::new(self.negative, sub(&self.digits, &other.digits)),
+        }
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    pub fn mul(&self, other: &Self) -> Self {
+        Self::new(self.negative != other.negative, mul(&self.digits, &other.digits))
+    }
+
+    pub fn div_rem(&self, other: &Self) -> Option<(Self, Self)> {
+        if other.is_zero() {
+            return None;
+        }
+
+        let (quotient, remainder) = div_rem(&self.digits, &other.digits);
+        Some((Self::new(self.negative != other.negative, quotient), Self::new(self.negative, remainder)))
+    }
+
+    pub fn pow(&self, mut exponent: u32) -> Self {
+        let mut base = self.clone();
+        let mut result = Self::from_i64(1);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(&base);
+            }
+
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul(&base);
+            }
+        }
+
+        result
+    }
+
+    pub fn not(&self) -> Self {
+        self.neg().sub(&Self::from_i64(1))
+    }
+
+    pub fn and(&self, other: &Self) -> Self {
+        self.bitwise(other, |x, y| x & y)
+    }
+
+    pub fn or(&self, other: &Self) -> Self {
+        self.bitwise(other, |x, y| x | y)
+    }
+
+    pub fn xor(&self, other: &Self) -> Self {
+        self.bitwise(other, |x, y| x ^ y)
+    }
+
+    pub fn shl(&self, shift: usize) -> Self {
+        Self::new(self.negative, shl(&self.digits, shift))
+    }
+
+    pub fn shr(&self, shift: usize) -> Self {
+        if self.negative {
+            let magnitude = sub(&self.digits, &[1]);
+            Self::new(true, add(&shr(&magnitude, shift), &[1]))
+        } else {
+            Self::new(false, shr(&self.digits, shift))
+        }
+    }
+
+    fn bitwise(&self, other: &Self, operation: fn(u32, u32) -> u32) -> Self {
+        let length = self.digits.len().max(other.digits.len()) + 1;
+        let x = self.to_complement(length);
+        let y = other.to_complement(length);
+        let digits = x.iter().zip(y.iter())
+            .map(|(x, y)| operation(*x, *y))
+            .collect();
+
+        Self::from_complement(digits)
+    }
+
+    fn to_complement(&self, length: usize) -> Vec<u32> {
+        let mut digits = self.digits.clone();
+        digits.resize(length, 0);
+        if self.negative {
+            negate(&mut digits);
+        }
+
+        digits
+    }
+
+    fn from_complement(mut digits: Vec<u32>) -> Self {
+        let negative = digits.last().is_some_and(|digit| digit >> 31 == 1);
+        if negative {
+            negate(&mut digits);
+        }
+
+        Self::new(negative, digits)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare(&self.digits, &other.digits),
+            (true, true) => compare(&other.digits, &self.digits),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        let mut chunks = Vec::new();
+        let mut digits = self.digits.clone();
+        while !digits.is_empty() {
+            let (quotient, remainder) = div_rem_small(&digits, 1_000_000_000);
+            chunks.push(remainder);
+            digits = quotient;
+        }
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        write!(f, "{}", chunks.pop().unwrap())?;
+        for chunk in chunks.iter().rev() {
+            write!(f, "{:09}", chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl GcTrace for BigInt {
+    fn size(&self) -> usize {
+        self.digits.capacity() * size_of::<u32>()
+    }
+}
+
+fn trim(digits: &mut Vec<u32>) {
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+}
+
+fn compare(x: &[u32], y: &[u32]) -> Ordering {
+    x.len().cmp(&y.len()).then_with(|| x.iter().rev().cmp(y.iter().rev()))
+}
+
+fn add(x: &[u32], y: &[u32]) -> Vec<u32> {
+    let mut digits = Vec::with_capacity(x.len().max(y.len()) + 1);
+    let mut carry = 0;
+    for i in 0 .. x.len().max(y.len()) {
+        let sum = *x.get(i).unwrap_or(&0) as u64 + *y.get(i).unwrap_or(&0) as u64 + carry;
+        digits.push(sum as u32);
+        carry = sum >> 32;
+    }
+
+    digits.push(carry as u32);
+    trim(&mut digits);
+    digits
+}
+
+fn sub(x: &[u32], y: &[u32]) -> Vec<u32> {
+    let mut digits = Vec::with_capacity(x.len());
+    let mut borrow = 0;
+    for (i, digit) in x.iter().enumerate() {
+        let difference = *digit as i64 - *y.get(i).unwrap_or(&0) as i64 - borrow;
+        digits.push(difference as u32);
+        borrow = (difference < 0) as i64;
+    }
+
+    trim(&mut digits);
+    digits
+}
+
+fn mul(x: &[u32], y: &[u32]) -> Vec<u32> {
+    let mut digits = vec![0; x.len() + y.len()];
+    for (i, a) in x.iter().enumerate() {
+        let mut carry = 0;
+        for (j, b) in y.iter().enumerate() {
+            let product = *a as u64 * *b as u64 + digits[i + j] as u64 + carry;
+            digits[i + j] = product as u32;
+            carry = product >> 32;
+        }
+
+        digits[i + y.len()] = carry as u32;
+    }
+
+    trim(&mut digits);
+    digits
+}
+
+fn mul_small_add(x: &[u32], factor: u32, addend: u32) -> Vec<u32> {
+    let mut digits = Vec::with_capacity(x.len() + 1);
+    let mut carry = addend as u64;
+    for digit in x.iter() {
+        let product = *digit as u64 * factor as u64 + carry;
+        digits.push(product as u32);
+        carry = product >> 32;
+    }
+
+    digits.push(carry as u32);
+    trim(&mut digits);
+    digits
+}
+
+fn div_rem_small(x: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut digits = vec![0; x.len()];
+    let mut remainder = 0;
+    for i in (0 .. x.len()).rev() {
+        let current = (remainder << 32) | x[i] as u64;
+        digits[i] = (current / divisor as u64) as u32;
+        remainder = current % divisor as u64;
+    }
+
+    trim(&mut digits);
+    (digits, remainder as u32)
+}
+
+fn div_rem(x: &[u32], y: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare(x, y) == Ordering::Less {
+        return (Vec::new(), x.to_vec());
+    }
+
+    if y.len() == 1 {
+        let (quotient, remainder) = div_rem_small(x, y[0]);
+        let mut remainder = vec![remainder];
+        trim(&mut remainder);
+        return (quotient, remainder);
+    }
+
+    let shift = y.last().unwrap().leading_zeros() as usize;
+    let v = shl(y, shift);
+    let mut u = shl(x, shift);
+    u.resize(x.len() + 1, 0);
+
+    let n = v.len();
+    let m = u.len() - n - 1;
+    let mut quotient = vec![0; m + 1];
+    for j in (0 ..= m).rev() {
+        let top = ((u[j + n] as u64) << 32) | u[j + n - 1] as u64;
+        let mut estimate = top / v[n - 1] as u64;
+        let mut rest = top % v[n - 1] as u64;
+        while estimate >= BASE || estimate * v[n - 2] as u64 > ((rest << 32) | u[j + n - 2] as u64) {
+            estimate -= 1;
+            rest += v[n - 1] as u64;
+            if rest >= BASE {
+                break;
+            }
+        }
+
+        let mut borrow = 0;
+        let mut carry = 0;
+        for i in 0 .. n {
+            let product = estimate * v[i] as u64 + carry;
+            carry = product >> 32;
+            let difference = u[i + j] as i64 - borrow - (product as u32) as i64;
+            u[i + j] = difference as u32;
+            borrow = (difference < 0) as i64;
+        }
+
+        let difference = u[j + n] as i64 - borrow - carry as i64;
+        u[j + n] = difference as u32;
+        if difference < 0 {
+            estimate -= 1;
+            let mut carry = 0;
+            for i in 0 .. n {
+                let sum = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = sum as u32;
+                carry = sum >> 32;
+            }
+
+            u[j + n] = u[j + n].wrapping_add(carry as u32);
+        }
+
+        quotient[j] = estimate as u32;
+    }
+
+    u.truncate(n);
+    trim(&mut quotient);
+    (quotient, shr(&u, shift))
+}
+
+fn shl(x: &[u32], shift: usize) -> Vec<u32> {
+    let (words, bits) = (shift / 32, shift % 32);
+    let mut digits = vec![0; words];
+    let mut carry = 0;
+    for digit in x.iter() {
+        digits.push((digit << bits) | carry);
+        carry = if bits == 0 { 0 } else { digit >> (32 - bits) };
+    }
+
+    digits.push(carry);
+    trim(&mut digits);
+    digits
+}
+
+fn shr(x: &[u32], shift: usize) -> Vec<u32> {
+    let (words, bits) = (shift / 32, shift % 32);
+    if words >= x.len() {
+        return Vec::new();
+    }
+
+    let mut digits = Vec::with_capacity(x.len() - words);
+    for i in words .. x.len() {
+        let high = if bits == 0 { 0 } else { x.get(i + 1).map_or(0, |digit| digit << (32 - bits)) };
+        digits.push((x[i] >> bits) | high);
+    }
+
+    trim(&mut digits);
+    digits
+}
+
+fn negate(digits: &mut [u32]) {
+    let mut carry = true;
+    for digit in digits.iter_mut() {
+        let (sum, overflow) = (!*digit).overflowing_add(carry as u32);
+        *digit = sum;
+        carry = overflow;
+    }
+}
@@ -1,4 +1,8 @@
-use crate::runtime::gc::{GcRef, GcTrace};
+use crate::ast::nodes::AExpr;
+use crate::memory::Ref;
+use crate::runtime::Variable;
+use crate::runtime::gc::{barrier, GcRef, GcTrace};
+use crate::runtime::scope::GcScope;
 use crate::runtime::value::Value;
 
 use std::collections::HashMap;
@@ -11,8 +15,17 @@ pub struct Class<'a> {
     pub name: Box<str>,
     parent: Option<GcClass<'a>>,
     pub generics: Box<[GcClass<'a>]>,
+    attributes: Vec<Attribute<'a>>,
     methods: HashMap<Box<str>, Value<'a>>,
     statics: HashMap<Box<str>, Value<'a>>,
+    revision: usize,
+}
+
+pub struct Attribute<'a> {
+    pub name: Box<str>,
+    pub r#type: GcClass<'a>,
+    pub scope: GcScope<'a>,
+    pub default: Option<Ref<AExpr>>,
 }
 
 impl<'a> Class<'a> {
@@ -26,13 +39,49 @@ impl<'a> Class<'a> {
             name: Box::from(name),
             generics,
             parent,
+            attributes: Vec::new(),
             methods,
-            statics: HashMap::new()
+            statics: HashMap::new(),
+            revision: 0,
+        }
+    }
+
+    pub fn add_attribute(&mut self, name: &str, r#type: GcClass<'a>, scope: GcScope<'a>, default: Option<Ref<AExpr>>) {
+        self.attributes.push(Attribute { name: Box::from(name), r#type, scope, default });
+    }
+
+    pub fn parent(&self) -> Option<GcClass<'a>> {
+        self.parent
+    }
+
+    pub fn attributes(&self) -> &[Attribute<'a>] {
+        &self.attributes
+    }
+
+    pub fn has_attributes(&self) -> bool {
+        if !self.attributes.is_empty() {
+            true
+        } else if let Some(parent) = self.parent {
+            parent.has_attributes()
+        } else {
+            false
+        }
+    }
+
+    pub fn init_attributes(&self, attributes: &mut HashMap<Box<str>, Variable<'a>>) {
+        if let Some(parent) = self.parent {
+            parent.init_attributes(attributes);
+        }
+
+        for attribute in self.attributes.iter() {
+            attributes.insert(attribute.name.clone(), Variable::undefined(attribute.r#type));
         }
     }
 
     pub fn add_method(&mut self, name: &str, method: Value<'a>) {
+        barrier(method);
         self.methods.insert(Box::from(name), method);
+        self.revision += 1;
     }
 
     pub fn get_method(&self, name: &str) -> Option<Value<'a>> {
@@ -45,7 +94,15 @@ impl<'a> Class<'a> {
         }
     }
 
+    pub fn revision(&self) -> usize {
+        match self.parent {
+            Some(parent) => self.revision + parent.revision(),
+            None => self.revision,
+        }
+    }
+
     pub fn add_static(&mut self, name: &str, r#static: Value<'a>) {
+        barrier(r#static);
         self.statics.insert(Box::from(name), r#static);
     }
 
@@ -90,6 +147,11 @@ impl GcTrace for Class<'_> {
             generic.trace();
         }
 
+        for attribute in self.attributes.iter_mut() {
+            attribute.r#type.trace();
+            attribute.scope.trace();
+        }
+
         for method in self.methods.values_mut() {
             method.trace();
         }
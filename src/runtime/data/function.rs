@@ -6,6 +6,7 @@ use crate::runtime::engine::Engine;
 use crate::runtime::flow::ResValue;
 use crate::runtime::scope::GcScope;
 use crate::runtime::value::Value;
+use crate::runtime::vm::Chunk;
 
 pub struct Function<'a> {
     pub name: Box<str>,
@@ -18,6 +19,7 @@ pub struct Function<'a> {
 
 pub enum FunctionBody {
     Block(Ref<ABlock>),
+    Chunk(Ref<Chunk>),
     Primitive(for<'a> fn(&mut Engine<'a>, &[Value<'a>]) -> ResValue<'a>),
 }
 
@@ -40,6 +42,24 @@ impl<'a> Function<'a> {
         }
     }
 
+    pub fn new_chunk(
+        name: &str,
+        scope: GcScope<'a>,
+        params: Box<[Param<'a>]>,
+        rest: Option<Param<'a>>,
+        ret: GcClass<'a>,
+        chunk: Ref<Chunk>
+    ) -> Self {
+        Self {
+            name: Box::from(name),
+            scope,
+            params,
+            rest,
+            ret,
+            body: FunctionBody::Chunk(chunk)
+        }
+    }
+
     pub fn new_primitive(
         name: &str,
         scope: GcScope<'a>,
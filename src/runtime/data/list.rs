@@ -2,9 +2,14 @@ use crate::runtime::gc::{GcRef, GcTrace};
 use crate::runtime::{Value, Variable};
 use crate::runtime::data::{Ref, GcClass};
 
+use std::mem::size_of;
+
 pub type GcList<'a> = GcRef<List<'a>>;
 
-pub struct List<'a>(Vec<Variable<'a>>);
+pub struct List<'a> {
+    variables: Vec<Variable<'a>>,
+    scanned: usize,
+}
 
 impl<'a> List<'a> {
     pub fn new(class: GcClass<'a>, values: &[Value<'a>]) -> Self {
@@ -13,44 +18,69 @@ impl<'a> List<'a> {
             .map(|value| Variable::value(class, value))
             .collect();
 
-        Self(variables)
+        Self { variables, scanned: 0 }
     }
 
     pub fn values(&self) -> Box<[Value<'a>]> {
-        self.0.iter()
+        self.variables.iter()
             .map(|variable| variable.content().unwrap())
             .collect()
     }
 
+    pub fn len(&self) -> usize {
+        self.variables.len()
+    }
+
     pub fn get(&self, index: usize) -> Value<'a> {
-        self.0[index].content().unwrap()
+        self.variables[index].content().unwrap()
     }
 
     pub fn get_ref(&mut self, index: usize) -> Ref<'a> {
-        self.0[index].get_ref()
+        self.scanned = self.scanned.min(index);
+        self.variables[index].get_ref()
     }
 
     pub fn insert(&mut self, class: GcClass<'a>, index: usize, value: Value<'a>) {
-        self.0.insert(index, Variable::value(class, value));
+        self.scanned = self.scanned.min(index);
+        self.variables.insert(index, Variable::value(class, value));
     }
 
     pub fn append(&mut self, class: GcClass<'a>, value: Value<'a>) {
-        self.0.push(Variable::value(class, value));
+        self.variables.push(Variable::value(class, value));
     }
 
     pub fn prepend(&mut self, class: GcClass<'a>, value: Value<'a>) {
-        self.0.insert(0, Variable::value(class, value));
+        self.scanned = 0;
+        self.variables.insert(0, Variable::value(class, value));
     }
 
-    pub fn remove(&mut self, index: usize) {
-        self.0.remove(index);
+    pub fn remove(&mut self, index: usize) -> Value<'a> {
+        self.scanned = self.scanned.min(index);
+        self.variables.remove(index).content().unwrap()
+    }
+
+    pub fn reverse(&mut self) {
+        self.scanned = 0;
+        self.variables.reverse();
     }
 }
 
 impl GcTrace for List<'_> {
     fn trace(&mut self) {
-        for element in self.0.iter_mut() {
+        for element in self.variables.iter_mut() {
             element.trace()
         }
     }
+
+    fn rescan(&mut self) {
+        for element in self.variables[self.scanned ..].iter_mut() {
+            element.trace()
+        }
+
+        self.scanned = self.variables.len();
+    }
+
+    fn size(&self) -> usize {
+        self.variables.capacity() * size_of::<Variable>()
+    }
 }
@@ -0,0 +1,100 @@
+use crate::runtime::gc::{barrier, GcRef, GcTrace};
+use crate::runtime::{Value, Variable};
+use crate::runtime::data::GcClass;
+
+use std::collections::HashMap;
+use std::mem::size_of;
+
+pub type GcMap<'a> = GcRef<Map<'a>>;
+
+pub struct Map<'a> {
+    entries: Vec<Entry<'a>>,
+    buckets: HashMap<i64, Vec<usize>>,
+}
+
+struct Entry<'a> {
+    hash: i64,
+    key: Value<'a>,
+    value: Variable<'a>,
+}
+
+impl<'a> Map<'a> {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            buckets: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn bucket(&self, hash: i64) -> Box<[usize]> {
+        self.buckets.get(&hash)
+            .map(|indices| indices.as_slice())
+            .unwrap_or(&[])
+            .into()
+    }
+
+    pub fn key(&self, index: usize) -> Value<'a> {
+        self.entries[index].key
+    }
+
+    pub fn get(&self, index: usize) -> Value<'a> {
+        self.entries[index].value.content().unwrap()
+    }
+
+    pub fn keys(&self) -> Box<[Value<'a>]> {
+        self.entries.iter()
+            .map(|entry| entry.key)
+            .collect()
+    }
+
+    pub fn values(&self) -> Box<[Value<'a>]> {
+        self.entries.iter()
+            .map(|entry| entry.value.content().unwrap())
+            .collect()
+    }
+
+    pub fn insert(&mut self, class: GcClass<'a>, hash: i64, key: Value<'a>, value: Value<'a>) {
+        barrier(key);
+        self.buckets.entry(hash).or_default().push(self.entries.len());
+        self.entries.push(Entry { hash, key, value: Variable::value(class, value) });
+    }
+
+    pub fn replace(&mut self, class: GcClass<'a>, index: usize, value: Value<'a>) {
+        self.entries[index].value = Variable::value(class, value);
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        let entry = self.entries.swap_remove(index);
+        let bucket = self.buckets.get_mut(&entry.hash).unwrap();
+        bucket.retain(|&other| other != index);
+        if bucket.is_empty() {
+            self.buckets.remove(&entry.hash);
+        }
+
+        if let Some(moved) = self.entries.get(index) {
+            let last = self.entries.len();
+            for other in self.buckets.get_mut(&moved.hash).unwrap().iter_mut() {
+                if *other == last {
+                    *other = index;
+                }
+            }
+        }
+    }
+}
+
+impl GcTrace for Map<'_> {
+    fn trace(&mut self) {
+        for entry in self.entries.iter_mut() {
+            entry.key.trace();
+            entry.value.trace();
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.entries.capacity() * size_of::<Entry>() + self.buckets.len() * size_of::<(i64, Vec<usize>)>()
+    }
+}
@@ -1,22 +1,30 @@
+pub mod bigint;
 pub mod class;
 pub mod function;
 pub mod generic;
 pub mod list;
+pub mod map;
 pub mod method;
+pub mod module;
 pub mod object;
+pub mod range;
 pub mod r#ref;
 pub mod string;
 
+pub use bigint::{BigInt, GcBigInt};
 pub use class::{Class, GcClass};
 pub use function::{Function, FunctionBody, GcFunction};
 pub use generic::{Generic, GenericBody, GcGeneric};
 pub use list::{List, GcList};
+pub use map::{Map, GcMap};
 pub use method::{Method, GcMethod};
+pub use module::{Module, GcModule};
 pub use object::{Object, GcObject};
+pub use range::{Range, GcRange};
 pub use r#ref::Ref;
 pub use string::{String, GcString};
 
-use crate::runtime::gc::GcTrace;
+use crate::runtime::gc::{GcRef, GcTrace};
 
 #[derive(Clone, Copy)]
 pub enum Data<'a> {
@@ -25,24 +33,51 @@ pub enum Data<'a> {
     Int(i64),
     Void(()),
     Ref(Ref<'a>),
+    BigInt(GcBigInt),
     Class(GcClass<'a>),
     Function(GcFunction<'a>),
     Generic(GcGeneric<'a>),
     List(GcList<'a>),
+    Map(GcMap<'a>),
     Method(GcMethod<'a>),
+    Module(GcModule<'a>),
     Object(GcObject<'a>),
+    Range(GcRange),
     String(GcString<'a>),
 }
 
+impl Data<'_> {
+    pub fn anonymize(self) -> Option<GcRef<()>> {
+        Some(match self {
+            Data::BigInt(bigint) => bigint.anonymize(),
+            Data::Class(class) => class.anonymize(),
+            Data::Function(function) => function.anonymize(),
+            Data::Generic(generic) => generic.anonymize(),
+            Data::List(list) => list.anonymize(),
+            Data::Map(map) => map.anonymize(),
+            Data::Method(method) => method.anonymize(),
+            Data::Module(module) => module.anonymize(),
+            Data::Object(object) => object.anonymize(),
+            Data::Range(range) => range.anonymize(),
+            Data::String(string) => string.anonymize(),
+            _ => return None,
+        })
+    }
+}
+
 impl GcTrace for Data<'_> {
     fn trace(&mut self) {
         match self {
+            Data::BigInt(bigint) => bigint.trace(),
             Data::Class(class) => class.trace(),
             Data::Function(function) => function.trace(),
             Data::Generic(generic) => generic.trace(),
             Data::List(list) => list.trace(),
+            Data::Map(map) => map.trace(),
             Data::Method(method) => method.trace(),
+            Data::Module(module) => module.trace(),
             Data::Object(object) => object.trace(),
+            Data::Range(range) => range.trace(),
             Data::Ref(r#ref) => r#ref.trace(),
             Data::String(string) => string.trace(),
             _ => (),
@@ -0,0 +1,43 @@
+use crate::runtime::gc::{GcRef, GcTrace};
+use crate::runtime::data::Ref;
+use crate::runtime::scope::GcScope;
+
+pub type GcModule<'a> = GcRef<Module<'a>>;
+
+pub struct Module<'a> {
+    pub name: Box<str>,
+    pub scope: GcScope<'a>,
+    pub loaded: bool,
+    exports: Vec<Box<str>>,
+}
+
+impl<'a> Module<'a> {
+    pub fn new(name: &str, scope: GcScope<'a>) -> Self {
+        Self {
+            name: Box::from(name),
+            scope,
+            loaded: false,
+            exports: Vec::new(),
+        }
+    }
+
+    pub fn export(&mut self, name: &str) {
+        if !self.exports.iter().any(|export| export.as_ref() == name) {
+            self.exports.push(Box::from(name));
+        }
+    }
+
+    pub fn get_export(&mut self, name: &str) -> Option<Ref<'a>> {
+        if self.exports.iter().any(|export| export.as_ref() == name) {
+            self.scope.get_ref(name)
+        } else {
+            None
+        }
+    }
+}
+
+impl GcTrace for Module<'_> {
+    fn trace(&mut self) {
+        self.scope.trace();
+    }
+}
@@ -3,6 +3,7 @@ use crate::runtime::gc::{GcRef, GcTrace};
 use crate::runtime::data::{Ref, GcClass};
 
 use std::collections::HashMap;
+use std::mem::size_of;
 
 pub type GcObject<'a> = GcRef<Object<'a>>;
 
@@ -30,4 +31,8 @@ impl GcTrace for Object<'_> {
             attribute.trace();
         }
     }
+
+    fn size(&self) -> usize {
+        self.attributes.capacity() * size_of::<(Box<str>, Variable)>()
+    }
 }
@@ -0,0 +1,54 @@
+use crate::runtime::gc::{GcRef, GcTrace};
+
+pub type GcRange = GcRef<Range>;
+
+#[derive(Clone, Copy)]
+pub struct Range {
+    pub start: i64,
+    pub end: i64,
+    pub step: i64,
+    pub inclusive: bool,
+}
+
+impl Range {
+    pub fn new(start: i64, end: i64, step: i64, inclusive: bool) -> Self {
+        Self { start, end, step, inclusive }
+    }
+
+    pub fn len(&self) -> i128 {
+        let (distance, step) = if self.step > 0 {
+            (self.end as i128 - self.start as i128, self.step as i128)
+        } else {
+            (self.start as i128 - self.end as i128, -(self.step as i128))
+        };
+
+        if self.inclusive {
+            if distance >= 0 { distance / step + 1 } else { 0 }
+        } else {
+            if distance > 0 { (distance - 1) / step + 1 } else { 0 }
+        }
+    }
+
+    pub fn get(&self, index: i128) -> i64 {
+        (self.start as i128 + self.step as i128 * index) as i64
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let offset = value as i128 - self.start as i128;
+        let step = self.step as i128;
+        offset % step == 0 && offset / step >= 0 && offset / step < self.len()
+    }
+
+    pub fn rev(&self) -> Self {
+        match self.len() {
+            0 => Self::new(self.start, self.start, -self.step, false),
+            length => Self::new(self.get(length - 1), self.start, -self.step, true),
+        }
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = i64> {
+        (0 .. self.len()).map(move |index| self.get(index))
+    }
+}
+
+impl GcTrace for Range {}
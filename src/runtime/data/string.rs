@@ -10,4 +10,8 @@ impl AsRef<str> for String {
     }
 }
 
-impl GcTrace for String {}
+impl GcTrace for String {
+    fn size(&self) -> usize {
+        self.0.len()
+    }
+}
@@ -2,15 +2,22 @@ use crate::ast::Pos;
 use crate::memory::Own;
 use crate::parser::{Ast, Code, Grammar};
 use crate::runtime::{Env, Value};
-use crate::runtime::gc::{Gc, GcCache, GcRef, GcTrace, GC_THRESHOLD};
-use crate::runtime::data::{Data, Class, Function, Generic, List, Method, Object, Ref, String, GcClass, GcGeneric};
-use crate::runtime::flow::{Res, ResValue};
+use crate::runtime::gc::{Gc, GcRef, GcTrace, GC_SLICE};
+use crate::runtime::data::{Data, BigInt, Class, Function, Generic, List, Map, Method, Module, Object, Range, Ref, String, GcClass, GcGeneric, GcModule};
+use crate::runtime::error::{Error, ErrorKind, Stack};
+use crate::runtime::flow::{Res, ResFlow, ResValue};
 use crate::runtime::generics::Generics;
+use crate::runtime::library;
 use crate::runtime::primitive::populate;
 use crate::runtime::scope::{Scope, GcScope};
 use crate::runtime::eval::errors::*;
+use crate::runtime::vm::Chunk;
 
+use std::collections::HashMap;
+use std::fs;
 use std::io::{Write, Read};
+use std::iter;
+use std::path::{Path, PathBuf};
 
 use super::flow::Flow;
 use super::frame::Frame;
@@ -27,45 +34,91 @@ impl<'a> Io<'a> {
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Backend {
+    Ast,
+    Vm,
+}
+
 pub struct Engine<'a> {
     pub io: Io<'a>,
+    pub backend: Backend,
     pub env: Env<'a>,
     pub frames: Vec<Frame<'a>>,
     pub scope: GcScope<'a>,
     pub grammar: &'a Grammar,
     pub codes: Vec<Own<Code>>,
-    gc: Gc,
-    cache: GcCache,
+    chunks: Vec<Own<Chunk>>,
+    pub paths: Vec<PathBuf>,
+    global: GcScope<'a>,
+    modules: HashMap<PathBuf, GcModule<'a>>,
+    module: Option<GcModule<'a>>,
+    pub gc: Gc,
     generics: Generics<'a>,
+    thrown: Option<Value<'a>>,
 }
 
 impl<'a> Engine<'a> {
     pub fn new(io: Io<'a>, grammar: &'a Grammar) -> Self {
         let mut engine = Self {
             io,
+            backend: Backend::Ast,
             grammar,
             env: Env::new(),
             frames: Vec::new(),
             scope: GcScope::null(),
             codes: Vec::new(),
+            chunks: Vec::new(),
+            paths: Vec::new(),
+            global: GcScope::null(),
+            modules: HashMap::new(),
+            module: None,
             gc: Gc::new(),
-            cache: GcCache::new(),
             generics: Generics::new(),
+            thrown: None,
         };
 
         engine.scope = engine.alloc(Scope::new(None));
+        engine.global = engine.scope;
         populate(&mut engine);
         engine
     }
 
+    pub fn parse(&mut self, name: Option<&str>, text: Box<str>) -> Option<Own<Code>> {
+        match Code::new(self.grammar, self.grammar.program, name, text) {
+            Ok(code) => Some(code),
+            Err(error) => {
+                writeln!(self.io.err, "{}", error.get_message()).unwrap();
+                None
+            },
+        }
+    }
+
+    pub fn eval(&mut self, text: Box<str>) -> ResValue<'a> {
+        let productions = [self.grammar.program, self.grammar.expression];
+        let code = match Code::parse(self.grammar, &productions, None, text) {
+            Ok(code) => code,
+            Err(mut error) => {
+                if let Some(code) = error.take_code() {
+                    self.codes.push(code);
+                }
+
+                return error_syntax(error.pos, &error.description());
+            },
+        };
+
+        self.run_inner(code)
+    }
+
     pub fn run(&mut self, code: Own<Code>) -> Option<Value<'a>> {
-        match self.run_inner(code) {
+        match self.guard(|engine| engine.run_inner(code)) {
             Ok(value) => Some(value),
             Err(error) => {
+                self.thrown = None;
                 writeln!(self.io.err, "{}", error.get_message()).unwrap();
                 writeln!(self.io.err, "STACK TRACE:").unwrap();
-                for frame in self.frames.iter().rev() {
-                    writeln!(self.io.err, "  in `{}` {}", frame.name(), frame.pos().print_pos()).unwrap();
+                for (name, pos) in error.stack.unwrap().iter() {
+                    writeln!(self.io.err, "  in `{}` {}", name, pos.print_pos()).unwrap();
                 }
 
                 None
@@ -73,12 +126,40 @@ impl<'a> Engine<'a> {
         }
     }
 
+    pub fn run_repl(&mut self, code: Own<Code>) -> Option<Value<'a>> {
+        match self.guard(|engine| engine.run_repl_inner(code)) {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.thrown = None;
+                writeln!(self.io.err, "{}", error.get_message()).unwrap();
+                None
+            },
+        }
+    }
+
+    fn run_repl_inner(&mut self, code: Own<Code>) -> ResValue<'a> {
+        self.frames.push(Frame::new_main(Pos::DUMMY, self.scope));
+        let result = self.execute(code.abstract_tree.as_ref().unwrap());
+
+        self.codes.push(code);
+        let value = match result? {
+            Flow::None(value) => value.read(Pos::DUMMY)?,
+            Flow::Jump(jump) => return error_jump(jump),
+        };
+
+        let value = if value.isa(self.env.void) {
+            value
+        } else {
+            value.call_method(self, Pos::DUMMY, "__str__", &[])?
+        };
+
+        self.frames.pop();
+        Ok(value)
+    }
+
     fn run_inner(&mut self, code: Own<Code>) -> ResValue<'a> {
         self.with_frame(Frame::new_main(Pos::DUMMY, self.scope), |engine| {
-            let result = match code.abstract_tree.as_ref().unwrap() {
-                Ast::Program(program) => program.eval(engine),
-                Ast::Expression(expr) => expr.eval(engine),
-            };
+            let result = engine.execute(code.abstract_tree.as_ref().unwrap());
 
             engine.codes.push(code);
             match result? {
@@ -89,29 +170,242 @@ impl<'a> Engine<'a> {
     }
 }
 
+impl<'a> Engine<'a> {
+    fn execute(&mut self, ast: &Ast) -> ResFlow<'a> {
+        match self.backend {
+            Backend::Ast => match ast {
+                Ast::Program(program) => program.eval(self),
+                Ast::Expression(expr) => expr.eval(self),
+            },
+            Backend::Vm => {
+                let chunk = Own::new(Chunk::compile(ast));
+                let r#ref = chunk.get_ref();
+                self.chunks.push(chunk);
+                r#ref.run(self)
+            },
+        }
+    }
+
+    pub fn import(&mut self, pos: Pos, path: &str) -> Res<GcModule<'a>> {
+        let directory = pos.source
+            .and_then(|source| source.name.as_ref().and_then(|name| Path::new(name.as_ref()).parent().map(Path::to_path_buf)))
+            .unwrap_or_default();
+
+        let Some((key, name, embedded)) = self.resolve(&directory, path) else {
+            return error_module(pos, path);
+        };
+
+        if let Some(module) = self.modules.get(&key).copied() {
+            if !module.loaded {
+                return error_module_cycle(pos, path);
+            }
+
+            return Ok(module);
+        }
+
+        let text = match embedded {
+            Some(text) => text.to_string(),
+            None => match fs::read_to_string(&key) {
+                Ok(text) => text,
+                Err(_) => return error_module(pos, path),
+            },
+        };
+
+        let Some(code) = self.parse(Some(&name), text.into_boxed_str()) else {
+            return error_module_parse(pos, path);
+        };
+
+        let scope = self.new_scope(self.global);
+        let mut module = self.alloc(Module::new(path, scope));
+        self.modules.insert(key.clone(), module);
+        let parent = self.module.replace(module);
+        let result = self.run_module(pos, module, &code);
+        self.codes.push(code);
+        self.module = parent;
+        if let Err(error) = result {
+            self.modules.remove(&key);
+            return Err(error);
+        }
+
+        module.loaded = true;
+        Ok(module)
+    }
+
+    fn resolve(&self, directory: &Path, path: &str) -> Option<(PathBuf, Box<str>, Option<&'static str>)> {
+        let files = iter::once(directory.join(path)).chain(self.paths.iter().map(|search| search.join(path)));
+        for file in files {
+            if let Ok(canonical) = file.canonicalize() {
+                return Some((canonical, file.to_string_lossy().into(), None));
+            }
+        }
+
+        let (name, text) = library::find(Path::new(path)).or_else(|| library::find(&directory.join(path)))?;
+        Some((PathBuf::from(name), Box::from(name), Some(text)))
+    }
+
+    pub fn export(&mut self, name: &str) {
+        if let Some(mut module) = self.module {
+            module.export(name);
+        }
+    }
+
+    fn run_module(&mut self, pos: Pos, module: GcModule<'a>, code: &Code) -> Res<()> {
+        let Some(ast @ Ast::Program(_)) = code.abstract_tree.as_ref() else {
+            return Ok(());
+        };
+
+        let scope = self.scope;
+        self.root_scope(scope);
+        self.scope = module.scope;
+        self.frames.push(Frame::new_module(pos, module));
+        let result = match self.execute(ast) {
+            Ok(Flow::Jump(jump)) => error_jump(jump),
+            Ok(Flow::None(_)) => Ok(()),
+            Err(error) => Err(error),
+        };
+
+        let result = self.unwind(result);
+        self.frames.pop();
+        self.scope = scope;
+        result
+    }
+}
+
 impl<'a> Engine<'a> {
     pub fn with_scope<T>(&mut self, f: impl FnOnce(&mut Self) -> Res<T>) -> Res<T> {
         let parent = self.scope;
         let child = self.new_scope(parent);
         self.scope = child;
-        let result = f(self)?;
+        let result = f(self);
         self.scope = parent;
-        Ok(result)
+        result
     }
 
     pub fn with_frame<T>(&mut self, frame: Frame<'a>, f: impl FnOnce(&mut Engine<'a>) -> Res<T>) -> Res<T> {
         let scope = self.scope;
+        self.root_scope(scope);
         self.scope = frame.scope();
         self.frames.push(frame);
-        let result = self.with_scope(f)?;
+        let result = self.with_scope(f);
+        let result = self.unwind(result);
         self.frames.pop().unwrap();
         self.scope = scope;
-        Ok(result)
+        result
     }
 
-    fn new_scope(&mut self, scope: GcScope<'a>) -> GcScope<'a> {
+    pub fn new_scope(&mut self, scope: GcScope<'a>) -> GcScope<'a> {
         self.alloc(Scope::new(Some(scope)))
     }
+
+    pub fn new_scope_slots(&mut self, scope: GcScope<'a>, names: &[Box<str>]) -> GcScope<'a> {
+        self.alloc(Scope::new_slots(Some(scope), names))
+    }
+
+    pub fn guard<T>(&mut self, f: impl FnOnce(&mut Self) -> Res<T>) -> Res<T> {
+        let frames = self.frames.len();
+        let scope = self.scope;
+        let roots = self.roots();
+        let result = f(self);
+        let result = self.unwind(result);
+        if result.is_err() {
+            self.frames.truncate(frames);
+            self.scope = scope;
+            self.release(roots);
+        }
+
+        result
+    }
+
+    fn unwind<T>(&self, result: Res<T>) -> Res<T> {
+        result.map_err(|mut error| {
+            if error.stack.is_none() {
+                error.stack = Some(self.stack());
+            }
+
+            error
+        })
+    }
+
+    fn stack(&self) -> Stack {
+        self.frames.iter()
+            .rev()
+            .map(|frame| (Box::from(frame.name()), frame.pos()))
+            .collect()
+    }
+}
+
+impl<'a> Engine<'a> {
+    pub fn throw<T>(&mut self, pos: Pos, value: Value<'a>) -> Res<T> {
+        if value.isa(self.env.error) {
+            let mut object = value.as_object();
+            if object.get_attr("stack", self.env.any).read(pos).is_err() {
+                let name = self.new_string(&pos.print_name());
+                let line = self.new_int(pos.line() as i64);
+                let column = self.new_int(pos.column() as i64);
+                let stack = self.new_stack(&self.stack());
+                self.set_error_attrs(value, name, line, column, stack)?;
+            }
+        }
+
+        let message = value.call_method(self, pos, "__str__", &[])?.as_string();
+        self.thrown = Some(value);
+        error_throw(pos, message.as_ref())
+    }
+
+    pub fn catch(&mut self, error: Error) -> ResValue<'a> {
+        if error.kind == ErrorKind::Throw {
+            return Ok(self.thrown.take().unwrap());
+        }
+
+        let class = match error.kind {
+            ErrorKind::Type     => self.env.error_type,
+            ErrorKind::Arity    => self.env.error_arity,
+            ErrorKind::Variable => self.env.error_variable,
+            ErrorKind::Jump     => self.env.error_jump,
+            _                   => self.env.error,
+        };
+
+        let value = self.new_object(class);
+        let message = self.new_string(&error.message);
+        value.as_object().get_attr("message", self.env.any).write(error.pos, message)?;
+        let name = self.new_string(&error.pos.print_name());
+        let line = self.new_int(error.pos.line() as i64);
+        let column = self.new_int(error.pos.column() as i64);
+        let stack = self.new_stack(error.stack.as_deref().unwrap_or(&[]));
+        self.set_error_attrs(value, name, line, column, stack)?;
+        Ok(value)
+    }
+
+    pub fn with_thrown<T>(&mut self, f: impl FnOnce(&mut Self) -> Res<T>) -> Res<T> {
+        let thrown = self.thrown.take();
+        if let Some(thrown) = thrown {
+            self.root(thrown);
+        }
+
+        let result = f(self);
+        if result.is_ok() {
+            self.thrown = thrown;
+        }
+
+        result
+    }
+
+    fn set_error_attrs(&mut self, value: Value<'a>, name: Value<'a>, line: Value<'a>, column: Value<'a>, stack: Value<'a>) -> Res<()> {
+        let mut object = value.as_object();
+        for (attr, value) in [("file", name), ("line", line), ("column", column), ("stack", stack)] {
+            object.get_attr(attr, self.env.any).write(Pos::DUMMY, value)?;
+        }
+
+        Ok(())
+    }
+
+    fn new_stack(&mut self, stack: &[(Box<str>, Pos)]) -> Value<'a> {
+        let frames = stack.iter()
+            .map(|(name, pos)| self.new_string(&format!("in `{}` {}", name, pos.print_pos())))
+            .collect::<Vec<_>>();
+
+        self.new_list(&frames)
+    }
 }
 
 impl<'a> Engine<'a> {
@@ -135,6 +429,13 @@ impl<'a> Engine<'a> {
         }
     }
 
+    pub fn read_slot(&mut self, pos: Pos, name: &str, depth: usize, index: usize) -> Res<Ref<'a>> {
+        match self.scope.get_slot(depth, index) {
+            Some(r#ref) => Ok(r#ref),
+            None => error_undeclared(pos, name),
+        }
+    }
+
     pub fn frame(&self) -> &Frame<'a> {
         self.frames.last().unwrap()
     }
@@ -152,27 +453,73 @@ impl<'a> Engine<'a> {
 
 impl<'a> Engine<'a> {
     pub fn alloc<T: GcTrace>(&mut self, object: T) -> GcRef<T> {
-        self.gc.alloc(object)
+        let r#ref = self.gc.alloc(object);
+        if self.gc.verify {
+            r#ref.locate(self.frames.last().map(|frame| (frame.name(), frame.pos())));
+        }
+
+        r#ref
+    }
+
+    pub fn root(&mut self, value: Value<'a>) {
+        if let Some(data) = value.data.anonymize() {
+            self.gc.roots().push(value.class);
+            self.gc.roots().push(data);
+        }
+    }
+
+    pub fn root_scope(&mut self, scope: GcScope<'a>) {
+        self.gc.roots().push(scope);
     }
 
-    pub fn with_gc<T: GcTrace>(&mut self, f: impl FnOnce(&mut Self) -> Res<GcRef<T>>) -> Res<GcRef<T>> {
-        if self.gc.allocations() > GC_THRESHOLD {
+    pub fn roots(&mut self) -> usize {
+        self.gc.roots().height()
+    }
+
+    pub fn release(&mut self, height: usize) {
+        self.gc.roots().truncate(height);
+    }
+
+    pub fn register(&mut self, roots: &mut dyn GcTrace) {
+        self.gc.roots().register(roots);
+    }
+
+    pub fn unregister(&mut self) {
+        self.gc.roots().unregister();
+    }
+
+    pub fn collect(&mut self) {
+        if !self.gc.marking() {
+            if !self.gc.pressure() {
+                if self.gc.nursery() {
+                    self.gc.begin_minor();
+                    self.trace();
+                    self.gc.mark(usize::MAX);
+                    self.gc.sweep_minor();
+                }
+
+                return;
+            }
+
+            self.gc.begin();
             self.trace();
-            self.gc.collect();
         }
 
-        self.cache.push();
-        let ret = f(self);
-        if let Ok(object) = ret {
-            self.cache.bubble(object);
+        if self.gc.incremental && !self.gc.mark(GC_SLICE) {
+            return;
         }
 
-        self.cache.pop();
-        ret
+        self.trace();
+        self.gc.mark(usize::MAX);
+        self.gc.sweep();
     }
 }
 
 impl<'a> Engine<'a> {
+    pub fn new_bigint(&mut self, bigint: BigInt) -> Value<'a> {
+        Value::new(self.env.bigint, Data::BigInt(self.alloc(bigint)))
+    }
+
     pub fn new_bool(&mut self, bool: bool) -> Value<'a> {
         Value::new(self.env.bool, Data::Bool(bool))
     }
@@ -206,15 +553,38 @@ impl<'a> Engine<'a> {
     }
 
     pub fn new_list(&mut self, values: &[Value<'a>]) -> Value<'a> {
-        Value::new(self.env.list_any, Data::List(self.alloc(List::new(self.env.any, values))))
+        self.new_list_class(self.env.list_any, values)
+    }
+
+    pub fn new_list_class(&mut self, class: GcClass<'a>, values: &[Value<'a>]) -> Value<'a> {
+        Value::new(class, Data::List(self.alloc(List::new(class.generics[0], values))))
+    }
+
+    pub fn new_list_of(&mut self, pos: Pos, element: GcClass<'a>, values: &[Value<'a>]) -> ResValue<'a> {
+        let class = self.get_generic(pos, self.env.list, Box::new([element]))?.as_class();
+        Ok(self.new_list_class(class, values))
+    }
+
+    pub fn new_map(&mut self, class: GcClass<'a>) -> Value<'a> {
+        Value::new(class, Data::Map(self.alloc(Map::new())))
     }
 
     pub fn new_method(&mut self, receiver: Value<'a>, function: Value<'a>) -> Value<'a> {
         Value::new(self.env.method, Data::Method(self.alloc(Method::new(receiver, function))))
     }
 
+    pub fn new_module(&mut self, module: GcModule<'a>) -> Value<'a> {
+        Value::new(self.env.module, Data::Module(module))
+    }
+
     pub fn new_object(&mut self, class: GcClass<'a>) -> Value<'a> {
-        Value::new(class, Data::Object(self.alloc(Object::new())))
+        let mut object = Object::new();
+        class.init_attributes(&mut object.attributes);
+        Value::new(class, Data::Object(self.alloc(object)))
+    }
+
+    pub fn new_range(&mut self, range: Range) -> Value<'a> {
+        Value::new(self.env.range, Data::Range(self.alloc(range)))
     }
 
     pub fn new_ref(&mut self, r#ref: Ref<'a>) -> Value<'a> {
@@ -232,9 +602,19 @@ impl<'a> Engine<'a> {
 
 impl GcTrace for Engine<'_> {
     fn trace(&mut self) {
-        self.cache.trace();
+        self.gc.trace();
         self.env.trace();
+        self.generics.trace();
         self.scope.trace();
+        self.global.trace();
+        for module in self.modules.values_mut() {
+            module.trace();
+        }
+
+        if let Some(thrown) = self.thrown.as_mut() {
+            thrown.trace();
+        }
+
         for frame in self.frames.iter_mut() {
             frame.trace();
         }
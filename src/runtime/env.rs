@@ -2,47 +2,59 @@ use crate::runtime::data::{GcClass, GcGeneric};
 use crate::runtime::gc::GcTrace;
 
 pub struct Env<'a> {
-    pub list:     GcGeneric<'a>,
-    pub list_any: GcClass<'a>,
-    pub any:      GcClass<'a>,
-    pub bool:     GcClass<'a>,
-    pub class:    GcClass<'a>,
-    pub float:    GcClass<'a>,
-    pub function: GcClass<'a>,
-    pub generic:  GcClass<'a>,
-    pub int:      GcClass<'a>,
-    pub method:   GcClass<'a>,
-    pub object:   GcClass<'a>,
-    pub r#ref:    GcClass<'a>,
-    pub string:   GcClass<'a>,
-    pub void:     GcClass<'a>,
+    pub list:           GcGeneric<'a>,
+    pub map:            GcGeneric<'a>,
+    pub list_any:       GcClass<'a>,
+    pub any:            GcClass<'a>,
+    pub bigint:         GcClass<'a>,
+    pub bool:           GcClass<'a>,
+    pub class:          GcClass<'a>,
+    pub error:          GcClass<'a>,
+    pub error_arity:    GcClass<'a>,
+    pub error_jump:     GcClass<'a>,
+    pub error_type:     GcClass<'a>,
+    pub error_variable: GcClass<'a>,
+    pub float:          GcClass<'a>,
+    pub function:       GcClass<'a>,
+    pub generic:        GcClass<'a>,
+    pub int:            GcClass<'a>,
+    pub method:         GcClass<'a>,
+    pub module:         GcClass<'a>,
+    pub object:         GcClass<'a>,
+    pub range:          GcClass<'a>,
+    pub r#ref:          GcClass<'a>,
+    pub string:         GcClass<'a>,
+    pub void:           GcClass<'a>,
 }
 
 impl Env<'_> {
     pub fn new() -> Self {
         Self {
-            list: GcGeneric::null(),
-            any: GcClass::null(), bool: GcClass::null(), class: GcClass::null(),
+            list: GcGeneric::null(), map: GcGeneric::null(),
+            any: GcClass::null(), bigint: GcClass::null(), bool: GcClass::null(), class: GcClass::null(),
+            error: GcClass::null(), error_arity: GcClass::null(), error_jump: GcClass::null(),
+            error_type: GcClass::null(), error_variable: GcClass::null(),
             float: GcClass::null(), function: GcClass::null(), generic: GcClass::null(),
-            int: GcClass::null(), method: GcClass::null(), list_any: GcClass::null(),
-            object: GcClass::null(), string: GcClass::null(), r#ref: GcClass::null(),
+            int: GcClass::null(), method: GcClass::null(), module: GcClass::null(), list_any: GcClass::null(),
+            object: GcClass::null(), range: GcClass::null(), string: GcClass::null(), r#ref: GcClass::null(),
             void: GcClass::null(),
         }
     }
 }
 
 impl<'a> Env<'a> {
-    fn get_classes_mut(&mut self) -> [&mut GcClass<'a>; 13] {
+    fn get_classes_mut(&mut self) -> [&mut GcClass<'a>; 21] {
         [
             &mut self.list_any,
-            &mut self.any, &mut self.bool, &mut self.class, &mut self.float, &mut self.function,
-            &mut self.generic, &mut self.int, &mut self.method, &mut self.object, &mut self.r#ref,
+            &mut self.any, &mut self.bigint, &mut self.bool, &mut self.class, &mut self.error, &mut self.error_arity,
+            &mut self.error_jump, &mut self.error_type, &mut self.error_variable, &mut self.float, &mut self.function,
+            &mut self.generic, &mut self.int, &mut self.method, &mut self.module, &mut self.object, &mut self.range, &mut self.r#ref,
             &mut self.string, &mut self.void,
         ]
     }
 
-    fn get_generics_mut(&mut self) -> [&mut GcGeneric<'a>; 1] {
-        [ &mut self.list ]
+    fn get_generics_mut(&mut self) -> [&mut GcGeneric<'a>; 2] {
+        [ &mut self.list, &mut self.map ]
     }
 }
 
@@ -51,5 +63,9 @@ impl GcTrace for Env<'_> {
         for class in self.get_classes_mut() {
             class.trace()
         }
+
+        for generic in self.get_generics_mut() {
+            generic.trace()
+        }
     }
 }
@@ -2,14 +2,29 @@ use crate::ast::Pos;
 
 use crate::runtime::flow::Res;
 
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Runtime,
+    Type,
+    Arity,
+    Variable,
+    Jump,
+    Throw,
+    Syntax,
+}
+
+pub type Stack = Box<[(Box<str>, Pos)]>;
+
 pub struct Error {
-    pos: Pos,
-    message: Box<str>,
+    pub kind: ErrorKind,
+    pub pos: Pos,
+    pub message: Box<str>,
+    pub stack: Option<Stack>,
 }
 
 impl Error {
-    pub fn new<T>(pos: Pos, message: String) -> Res<T> {
-        Err(Self { pos, message: Box::from(message) })
+    pub fn new<T>(kind: ErrorKind, pos: Pos, message: String) -> Res<T> {
+        Err(Self { kind, pos, message: Box::from(message), stack: None })
     }
 
     pub fn get_message(&self) -> Box<str> {
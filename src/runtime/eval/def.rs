@@ -1,8 +1,9 @@
 use crate::ast::nodes::*;
 use crate::memory::Ref;
 use crate::runtime::{Engine, Value};
-use crate::runtime::data::{Class, Function, Param, Generic};
+use crate::runtime::data::{Class, Function, Param, Generic, GcClass};
 use crate::runtime::flow::{Res, ResValue};
+use crate::runtime::vm::Chunk;
 
 use std::collections::HashMap;
 
@@ -60,20 +61,53 @@ fn make_generic<'a>(node: &ADef, engine: &mut Engine<'a>) -> Res<Value<'a>> {
 }
 
 fn make_class<'a>(node: &AClass, engine: &mut Engine<'a>) -> Res<Value<'a>> {
-    let methods = node.methods.iter()
-        .map(|method| Ok(make_function(method, engine)?))
-        .collect::<Res<Box<_>>>()?
-        .iter()
+    let methods = node.members.iter()
+        .filter_map(|member| match member {
+            AMember::Method(method) => Some(make_function(method, engine)),
+            AMember::Attribute(_) => None,
+        })
+        .collect::<Res<Box<_>>>()?;
+
+    make_class_methods(node, &methods, engine)
+}
+
+pub(crate) fn make_class_methods<'a>(node: &AClass, methods: &[Value<'a>], engine: &mut Engine<'a>) -> Res<Value<'a>> {
+    let methods = methods.iter()
         .copied()
         .map(|method| (method.as_function().name.clone(), method))
         .collect::<HashMap<_, _>>();
 
     let parent = read_type(&node.parent, engine)?.unwrap_or(engine.env.object);
-    let class = Class::new(&node.name, Some(parent), Box::from(engine.frame().generics()), methods);
+    let mut class = Class::new(&node.name, Some(parent), Box::from(engine.frame().generics()), methods);
+    for member in node.members.iter() {
+        if let AMember::Attribute(attribute) = member {
+            make_attribute(attribute, &mut class, engine)?;
+        }
+    }
+
     Ok(engine.new_class(class))
 }
 
-fn make_function<'a>(node: &AFunction, engine: &mut Engine<'a>) -> Res<Value<'a>> {
+fn make_attribute<'a>(node: &AAttribute, class: &mut Class<'a>, engine: &mut Engine<'a>) -> Res<()> {
+    let r#type = read_type_any(&node.r#type, engine)?;
+    let default = node.default.as_ref().map(|default| Ref::new(default.as_ref()));
+    class.add_attribute(&node.name, r#type, engine.scope, default);
+    Ok(())
+}
+
+pub(super) fn make_function<'a>(node: &AFunction, engine: &mut Engine<'a>) -> Res<Value<'a>> {
+    let (params, rest, ret) = make_signature(node, engine)?;
+    let function = Function::new_block(&node.name, engine.scope, params, rest, ret, Ref::new(&node.body));
+    Ok(engine.new_function(function))
+}
+
+pub(crate) fn make_function_chunk<'a>(node: &AFunction, chunk: &Chunk, engine: &mut Engine<'a>) -> Res<Value<'a>> {
+    let (params, rest, ret) = make_signature(node, engine)?;
+    let function = Function::new_chunk(&node.name, engine.scope, params, rest, ret, Ref::new(chunk));
+    Ok(engine.new_function(function))
+}
+
+fn make_signature<'a>(node: &AFunction, engine: &mut Engine<'a>) -> Res<(Box<[Param<'a>]>, Option<Param<'a>>, GcClass<'a>)> {
     let params = node.params.iter()
         .map(|param| make_parameter(param, engine))
         .collect::<Res<Box<[_]>>>()?;
@@ -85,8 +119,7 @@ fn make_function<'a>(node: &AFunction, engine: &mut Engine<'a>) -> Res<Value<'a>
     };
 
     let ret = read_type_any(&node.ret, engine)?;
-    let function = Function::new_block(&node.name, engine.scope, params, rest, ret, Ref::new(&node.body));
-    Ok(engine.new_function(function))
+    Ok((params, rest, ret))
 }
 
 fn make_parameter<'a>(node: &AParameter, engine: &mut Engine<'a>) -> Res<Param<'a>> {
@@ -1,41 +1,109 @@
 use crate::ast::Pos;
 use crate::runtime::Value;
 use crate::runtime::data::{GcClass, GcFunction, GcGeneric};
-use crate::runtime::error::Error;
+use crate::runtime::error::{Error, ErrorKind};
 use crate::runtime::flow::{Jump, Res};
 
 pub fn error_generic_apply_arity<'a, T>(pos: Pos, generic: GcGeneric<'a>, args: &[GcClass<'a>]) -> Res<T> {
-    Error::new(pos, format!("`{}` has {} generic parameters but {} arguments were supplied", generic.name, generic.params.len(), args.len()))
+    Error::new(ErrorKind::Arity, pos, format!("`{}` has {} generic parameters but {} arguments were supplied", generic.name, generic.params.len(), args.len()))
 }
 
 pub fn error_generic_type<T>(pos: Pos, param: GcClass<'_>, arg: GcClass<'_>) -> Res<T> {
-    Error::new(pos, format!("expected subclass of `{}` but found class `{}`", arg.name, param.name))
+    Error::new(ErrorKind::Type, pos, format!("expected subclass of `{}` but found class `{}`", arg.name, param.name))
 }
 
 pub fn error_function_call_arity<T>(pos: Pos, function: GcFunction<'_>, args: &[Value<'_>]) -> Res<T> {
-    Error::new(pos, format!("function `{}` expects {} arguments but {} were supplied", function.name, function.params.len(), args.len()))
+    Error::new(ErrorKind::Arity, pos, format!("function `{}` expects {} arguments but {} were supplied", function.name, function.params.len(), args.len()))
 }
 
 pub fn error_function_call_rest_arity<T>(pos: Pos, function: GcFunction<'_>, args: &[Value<'_>]) -> Res<T> {
-    Error::new(pos, format!("function `{}` expects at least {} arguments but {} were supplied", function.name, function.params.len(), args.len()))
+    Error::new(ErrorKind::Arity, pos, format!("function `{}` expects at least {} arguments but {} were supplied", function.name, function.params.len(), args.len()))
+}
+
+pub fn error_class_init_arity<T>(pos: Pos, class: GcClass<'_>, args: &[Value<'_>]) -> Res<T> {
+    Error::new(ErrorKind::Arity, pos, format!("class `{}` has no `init` method but {} arguments were supplied", class.name, args.len()))
+}
+
+pub fn error_member<T>(pos: Pos, class: GcClass<'_>, name: &str) -> Res<T> {
+    Error::new(ErrorKind::Variable, pos, format!("class `{}` has no member `{name}`", class.name))
+}
+
+pub fn error_module<T>(pos: Pos, path: &str) -> Res<T> {
+    Error::new(ErrorKind::Runtime, pos, format!("cannot find module `{path}`"))
+}
+
+pub fn error_module_cycle<T>(pos: Pos, path: &str) -> Res<T> {
+    Error::new(ErrorKind::Runtime, pos, format!("module `{path}` is imported while it is being loaded"))
+}
+
+pub fn error_module_parse<T>(pos: Pos, path: &str) -> Res<T> {
+    Error::new(ErrorKind::Runtime, pos, format!("cannot parse module `{path}`"))
+}
+
+pub fn error_export<T>(pos: Pos, module: &str, name: &str) -> Res<T> {
+    Error::new(ErrorKind::Variable, pos, format!("module `{module}` has no export `{name}`"))
 }
 
 pub fn error_type<T>(pos: Pos, value: Value<'_>, r#type: GcClass<'_>) -> Res<T> {
-    Error::new(pos, format!("expected value of type `{}` but found value of type `{}`", r#type.name, value.class.name))
+    Error::new(ErrorKind::Type, pos, format!("expected value of type `{}` but found value of type `{}`", r#type.name, value.class.name))
+}
+
+pub fn error_index<T>(pos: Pos, index: i64, length: usize) -> Res<T> {
+    Error::new(ErrorKind::Runtime, pos, format!("index {index} is out of bounds for length {length}"))
+}
+
+pub fn error_slice<T>(pos: Pos, start: i64, end: i64, length: usize) -> Res<T> {
+    Error::new(ErrorKind::Runtime, pos, format!("slice {start} .. {end} is out of bounds for length {length}"))
+}
+
+pub fn error_convert<T>(pos: Pos, value: &str, r#type: GcClass<'_>) -> Res<T> {
+    Error::new(ErrorKind::Runtime, pos, format!("cannot convert `{value}` to `{}`", r#type.name))
+}
+
+pub fn error_division<T>(pos: Pos) -> Res<T> {
+    Error::new(ErrorKind::Runtime, pos, String::from("division by zero"))
+}
+
+pub fn error_overflow<T>(pos: Pos, operation: &str) -> Res<T> {
+    Error::new(ErrorKind::Runtime, pos, format!("integer overflow in `{operation}`"))
+}
+
+pub fn error_repeat<T>(pos: Pos, count: usize) -> Res<T> {
+    Error::new(ErrorKind::Runtime, pos, format!("string is too long to be repeated {count} times"))
+}
+
+pub fn error_step<T>(pos: Pos, step: i64) -> Res<T> {
+    Error::new(ErrorKind::Runtime, pos, format!("range step must be positive but found {step}"))
+}
+
+pub fn error_parse<T>(pos: Pos, string: &str, r#type: GcClass<'_>) -> Res<T> {
+    Error::new(ErrorKind::Runtime, pos, format!("cannot parse `{string}` as `{}`", r#type.name))
 }
 
 pub fn error_jump<T>(jump: Jump) -> Res<T> {
-    Error::new(jump.pos, format!("invalid jump"))
+    Error::new(ErrorKind::Jump, jump.pos, String::from("invalid jump"))
 }
 
 pub fn error_jump_loop<T>(jump: Jump) -> Res<T> {
-    Error::new(jump.pos, format!("invalid loop jump"))
+    Error::new(ErrorKind::Jump, jump.pos, String::from("invalid loop jump"))
+}
+
+pub fn error_key<T>(pos: Pos, key: &str) -> Res<T> {
+    Error::new(ErrorKind::Runtime, pos, format!("key `{key}` not found"))
+}
+
+pub fn error_syntax<T>(pos: Pos, message: &str) -> Res<T> {
+    Error::new(ErrorKind::Syntax, pos, message.to_string())
+}
+
+pub fn error_throw<T>(pos: Pos, message: &str) -> Res<T> {
+    Error::new(ErrorKind::Throw, pos, format!("uncaught {message}"))
 }
 
 pub fn error_undeclared<T>(pos: Pos, name: &str) -> Res<T> {
-    Error::new(pos, format!("undeclared variable `{name}`"))
+    Error::new(ErrorKind::Variable, pos, format!("undeclared variable `{name}`"))
 }
 
 pub fn error_undefined<T>(pos: Pos) -> Res<T> {
-    Error::new(pos, format!("undefined variable"))
+    Error::new(ErrorKind::Variable, pos, String::from("undefined variable"))
 }
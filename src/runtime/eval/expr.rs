@@ -1,10 +1,15 @@
 use crate::ast::Pos;
 use crate::ast::nodes::*;
-use crate::runtime::data::Ref;
+use crate::runtime::Value;
+use crate::runtime::data::{BigInt, Data, Ref};
 use crate::runtime::engine::Engine;
 use crate::runtime::flow::{Flow, FlowT, Jump, JumpKind, ResFlow, ResFlowT};
+use super::def::make_function;
+use super::errors::error_type;
 use super::r#type::read_type_any;
 
+use std::iter::zip;
+
 macro_rules! flow {
     ( $flow:expr ) => {{
         use crate::runtime::flow::FlowT;
@@ -29,63 +34,77 @@ macro_rules! jump_flow {
 impl AExpr {
     pub fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
         match self {
-            AExpr::Void(void)           => void.eval(engine),
-            AExpr::Bool(bool)           => bool.eval(engine),
-            AExpr::Int(int)             => int.eval(engine),
-            AExpr::Float(float)         => float.eval(engine),
-            AExpr::String(string)       => string.eval(engine),
-            AExpr::Ident(ident)         => ident.eval(engine),
-            AExpr::Var(var)             => var.eval(engine),
-            AExpr::Chain(chain)         => chain.eval(engine),
-            AExpr::Apply(apply)         => apply.eval(engine),
-            AExpr::Call(call)           => call.eval(engine),
-            AExpr::Continue(r#continue) => r#continue.eval(engine),
-            AExpr::Break(r#break)       => r#break.eval(engine),
-            AExpr::Return(r#return)     => r#return.eval(engine),
-            AExpr::Preop(preop)         => preop.eval(engine),
-            AExpr::Binop(binop)         => binop.eval(engine),
-            AExpr::Or(or)               => or.eval(engine),
-            AExpr::And(and)             => and.eval(engine),
-            AExpr::Block(block)         => block.eval(engine),
-            AExpr::If(r#if)             => r#if.eval(engine),
-            AExpr::Loop(r#loop)         => r#loop.eval(engine),
-            AExpr::While(r#while)       => r#while.eval(engine),
-            AExpr::For(r#for)           => r#for.eval(engine),
-            AExpr::Assign(assign)       => assign.eval(engine),
+            AExpr::Void(void)                   => void.eval(engine),
+            AExpr::Bool(bool)                   => bool.eval(engine),
+            AExpr::Int(int)                     => int.eval(engine),
+            AExpr::Float(float)                 => float.eval(engine),
+            AExpr::String(string)               => string.eval(engine),
+            AExpr::Interpolation(interpolation) => interpolation.eval(engine),
+            AExpr::Ident(ident)                 => ident.eval(engine),
+            AExpr::Var(var)                     => var.eval(engine),
+            AExpr::Chain(chain)                 => chain.eval(engine),
+            AExpr::Apply(apply)                 => apply.eval(engine),
+            AExpr::Call(call)                   => call.eval(engine),
+            AExpr::Continue(r#continue)         => r#continue.eval(engine),
+            AExpr::Break(r#break)               => r#break.eval(engine),
+            AExpr::Return(r#return)             => r#return.eval(engine),
+            AExpr::Throw(throw)                 => throw.eval(engine),
+            AExpr::Preop(preop)                 => preop.eval(engine),
+            AExpr::Binop(binop)                 => binop.eval(engine),
+            AExpr::Or(or)                       => or.eval(engine),
+            AExpr::And(and)                     => and.eval(engine),
+            AExpr::Block(block)                 => block.eval(engine),
+            AExpr::If(r#if)                     => r#if.eval(engine),
+            AExpr::Loop(r#loop)                 => r#loop.eval(engine),
+            AExpr::While(r#while)               => r#while.eval(engine),
+            AExpr::For(r#for)                   => r#for.eval(engine),
+            AExpr::Try(r#try)                   => r#try.eval(engine),
+            AExpr::Assign(assign)               => assign.eval(engine),
+            AExpr::Function(function)           => function.eval(engine),
         }
     }
 
     pub fn pos(&self) -> Pos {
         match self {
-            AExpr::Void     (node) => node.pos,
-            AExpr::Bool     (node) => node.pos,
-            AExpr::Int      (node) => node.pos,
-            AExpr::Float    (node) => node.pos,
-            AExpr::String   (node) => node.pos,
-            AExpr::Ident    (node) => node.pos,
-            AExpr::Var      (node) => node.pos,
-            AExpr::Chain    (node) => node.pos,
-            AExpr::Apply    (node) => node.pos,
-            AExpr::Call     (node) => node.pos,
-            AExpr::Continue (node) => node.pos,
-            AExpr::Break    (node) => node.pos,
-            AExpr::Return   (node) => node.pos,
-            AExpr::Preop    (node) => node.pos,
-            AExpr::Binop    (node) => node.pos,
-            AExpr::Or       (node) => node.pos,
-            AExpr::And      (node) => node.pos,
-            AExpr::Block    (node) => node.pos,
-            AExpr::If       (node) => node.pos,
-            AExpr::Loop     (node) => node.pos,
-            AExpr::While    (node) => node.pos,
-            AExpr::For      (node) => node.pos,
-            AExpr::Assign   (node) => node.pos,
+            AExpr::Void          (node) => node.pos,
+            AExpr::Bool          (node) => node.pos,
+            AExpr::Int           (node) => node.pos,
+            AExpr::Float         (node) => node.pos,
+            AExpr::String        (node) => node.pos,
+            AExpr::Interpolation (node) => node.pos,
+            AExpr::Ident         (node) => node.pos,
+            AExpr::Var           (node) => node.pos,
+            AExpr::Chain         (node) => node.pos,
+            AExpr::Apply         (node) => node.pos,
+            AExpr::Call          (node) => node.pos,
+            AExpr::Continue      (node) => node.pos,
+            AExpr::Break         (node) => node.pos,
+            AExpr::Return        (node) => node.pos,
+            AExpr::Throw         (node) => node.pos,
+            AExpr::Preop         (node) => node.pos,
+            AExpr::Binop         (node) => node.pos,
+            AExpr::Or            (node) => node.pos,
+            AExpr::And           (node) => node.pos,
+            AExpr::Block         (node) => node.pos,
+            AExpr::If            (node) => node.pos,
+            AExpr::Loop          (node) => node.pos,
+            AExpr::While         (node) => node.pos,
+            AExpr::For           (node) => node.pos,
+            AExpr::Try           (node) => node.pos,
+            AExpr::Assign        (node) => node.pos,
+            AExpr::Function      (node) => node.pos,
         }
     }
 
     pub fn read<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
         let value = flow!(self.eval(engine));
-        Flow::none(value.read(self.pos())?)
+        let Data::Ref(r#ref) = value.data else {
+            return Flow::none(value);
+        };
+
+        let value = r#ref.read(self.pos())?;
+        engine.root(value);
+        Flow::none(value)
     }
 
     pub fn read_bool<'a>(&self, engine: &mut Engine<'a>) -> ResFlowT<'a, bool> {
@@ -115,18 +134,25 @@ impl AExprBool {
 
 impl AExprInt {
     fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
-        let literal = self.literal.replace("_", "");
-        let int = match literal.chars().nth(1) {
-            Some('b') => i64::from_str_radix(&literal[2..], 2).unwrap(),
-            Some('o') => i64::from_str_radix(&literal[2..], 8).unwrap(),
-            Some('x') => i64::from_str_radix(&literal[2..], 16).unwrap(),
-            _ => literal.parse::<i64>().unwrap(),
-        };
-
-        Flow::none(engine.new_int(int))
+        match parse_int(&self.literal) {
+            Ok(int) => Flow::none(engine.new_int(int)),
+            Err(bigint) => Flow::none(engine.new_bigint(bigint)),
+        }
     }
 }
 
+pub(crate) fn parse_int(literal: &str) -> Result<i64, BigInt> {
+    let literal = literal.replace("_", "");
+    let (digits, radix) = match literal.chars().nth(1) {
+        Some('b') => (&literal[2..], 2),
+        Some('o') => (&literal[2..], 8),
+        Some('x') => (&literal[2..], 16),
+        _ => (literal.as_str(), 10),
+    };
+
+    i64::from_str_radix(digits, radix).map_err(|_| BigInt::parse(digits, radix).unwrap())
+}
+
 impl AExprFloat {
     fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
         let literal = self.literal.replace("_", "");
@@ -137,10 +163,53 @@ impl AExprFloat {
 
 impl AExprString {
     fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
-        Flow::none(engine.new_string(&self.literal[1 .. self.literal.len() - 1]))
+        Flow::none(engine.new_string(&unescape(&self.literal)))
     }
 }
 
+impl AExprInterpolation {
+    fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
+        let mut string = unescape(&self.literals[0]);
+        for (expr, literal) in zip(self.exprs.iter(), self.literals[1 ..].iter()) {
+            let value = flow!(expr.read(engine));
+            let value = value.call_method(engine, expr.pos(), "__str__", &[])?;
+            string.push_str(value.as_string().as_ref());
+            string.push_str(&unescape(literal));
+        }
+
+        Flow::none(engine.new_string(&string))
+    }
+}
+
+pub(crate) fn unescape(literal: &str) -> String {
+    let mut string = String::new();
+    let mut characters = literal[1 .. literal.len() - 1].chars();
+    while let Some(character) = characters.next() {
+        if character != '\\' {
+            string.push(character);
+            continue;
+        }
+
+        string.push(match characters.next().unwrap() {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            'u' => {
+                let digits = characters.by_ref()
+                    .skip(1)
+                    .take_while(|&character| character != '}')
+                    .collect::<String>();
+
+                char::from_u32(u32::from_str_radix(&digits, 16).unwrap()).unwrap()
+            },
+            character => character,
+        });
+    }
+
+    string
+}
+
 impl AExprIdent {
     fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
         let r#ref = engine.read(self.pos, &self.ident)?;
@@ -182,14 +251,13 @@ impl AExprApply {
 impl AExprCall {
     fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
         let receiver = flow!(self.expr.read(engine));
-        let function = receiver.class.get_method("__cl__").expect("TODO").as_function();
         let mut values = Vec::new();
         for arg in self.args.iter() {
             values.push(flow!(arg.read(engine)))
         }
 
         let args = engine.new_list(&values);
-        Flow::none(function.call(engine, self.pos, &[receiver, args])?)
+        Flow::none(receiver.call_method_self(engine, self.pos, "__cl__", &[receiver, args])?)
     }
 }
 
@@ -229,18 +297,27 @@ impl AExprReturn {
     }
 }
 
+impl AExprThrow {
+    fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
+        let value = flow!(self.expr.read(engine));
+        engine.throw(self.pos, value)
+    }
+}
+
 impl AExprPreop {
     fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
         let value = flow!(self.expr.read(engine));
-        let name = match self.op.as_ref() {
-            "~" => "__bnot__",
-            "+" => "__pos__",
-            "-" => "__neg__",
-            "!" => "__not__",
-            _   => panic!(),
-        };
+        Flow::none(value.call_method(engine, self.pos, preop_method(&self.op), &[])?)
+    }
+}
 
-        Flow::none(value.call_method(engine, self.pos, name, &[])?)
+pub(crate) fn preop_method(op: &str) -> &'static str {
+    match op {
+        "~" => "__bnot__",
+        "+" => "__pos__",
+        "-" => "__neg__",
+        "!" => "__not__",
+        _   => panic!(),
     }
 }
 
@@ -248,29 +325,34 @@ impl AExprBinop {
     fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
         let left = flow!(self.left.read(engine));
         let right = flow!(self.right.read(engine));
-        let name = match self.op.as_ref() {
-            "==" => "__eq__",
-            "!=" => "__ne__",
-            "<" => "__lt__",
-            ">" => "__gt__",
-            "<=" => "__le__",
-            ">=" => "__ge__",
-            "+" => "__add__",
-            "-" => "__sub__",
-            "*" => "__mul__",
-            "/" => "__div__",
-            "%" => "__rem__",
-            "&" => "__band__",
-            "|" => "__bor__",
-            "^" => "__bxor__",
-            "<<" => "__bls__",
-            ">>" => "__brs__",
-            "<<<" => "__bcls__",
-            ">>>" => "__bcrs__",
-            _   => panic!(),
-        };
+        Flow::none(left.call_method(engine, self.pos, binop_method(&self.op), &[right])?)
+    }
+}
 
-        Flow::none(left.call_method(engine, self.pos, name, &[right])?)
+pub(crate) fn binop_method(op: &str) -> &'static str {
+    match op {
+        "==" => "__eq__",
+        "!=" => "__ne__",
+        "<" => "__lt__",
+        ">" => "__gt__",
+        "<=" => "__le__",
+        ">=" => "__ge__",
+        "+" => "__add__",
+        "-" => "__sub__",
+        "*" => "__mul__",
+        "/" => "__div__",
+        "%" => "__rem__",
+        "**" => "__pow__",
+        "&" => "__band__",
+        "|" => "__bor__",
+        "^" => "__bxor__",
+        "<<" => "__bls__",
+        ">>" => "__brs__",
+        "<<<" => "__bcls__",
+        ">>>" => "__bcrs__",
+        ".." => "__range__",
+        "..=" => "__range_eq__",
+        _   => panic!(),
     }
 }
 
@@ -299,8 +381,11 @@ impl AExprAnd {
 impl ABlock {
     pub fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
         engine.with_scope(|engine| {
+            let roots = engine.roots();
             for stmt in self.stmts.iter() {
                 jump_flow!(stmt.eval_stmt(engine));
+                engine.release(roots);
+                engine.collect();
             }
 
             if let Some(expr) = self.expr.as_ref() {
@@ -314,8 +399,11 @@ impl ABlock {
 
 impl AProgram {
     pub fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
+        let roots = engine.roots();
         for stmt in self.stmts.iter() {
             jump_flow!(stmt.eval_stmt(engine));
+            engine.release(roots);
+            engine.collect();
         }
 
         Flow::none(engine.new_void())
@@ -336,22 +424,22 @@ impl AIf {
 
 impl ALoop {
     fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
-        let mut values = Vec::new();
+        let list = engine.new_list(&[]);
+        let mut values = list.as_list();
+        let roots = engine.roots();
         loop {
             match self.body.eval(engine)? {
                 Flow::None(value) => {
-                    values.push(value)
+                    values.append(engine.env.any, value);
                 },
                 Flow::Jump(Jump { jump: JumpKind::Continue, value, .. }) => {
                     if let Some(value) = value {
-                        values.push(value);
+                        values.append(engine.env.any, value);
                     }
-
-                    continue;
                 },
                 Flow::Jump(Jump { jump: JumpKind::Break, value, .. }) => {
                     if let Some(value) = value {
-                        values.push(value);
+                        values.append(engine.env.any, value);
                     }
 
                     break;
@@ -360,30 +448,33 @@ impl ALoop {
                     return Ok(Flow::Jump(jump));
                 },
             }
+
+            engine.release(roots);
+            engine.collect();
         }
 
-        Flow::none(engine.new_list(&values))
+        Flow::none(list)
     }
 }
 
 impl AWhile {
     fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
-        let mut values = Vec::new();
+        let list = engine.new_list(&[]);
+        let mut values = list.as_list();
+        let roots = engine.roots();
         while flow!(self.cond.read_bool(engine)) {
             match self.body.eval(engine)? {
                 Flow::None(value) => {
-                    values.push(value)
+                    values.append(engine.env.any, value);
                 },
                 Flow::Jump(Jump { jump: JumpKind::Continue, value, .. }) => {
                     if let Some(value) = value {
-                        values.push(value);
+                        values.append(engine.env.any, value);
                     }
-
-                    continue;
                 },
                 Flow::Jump(Jump { jump: JumpKind::Break, value, .. }) => {
                     if let Some(value) = value {
-                        values.push(value);
+                        values.append(engine.env.any, value);
                     }
 
                     break;
@@ -392,32 +483,56 @@ impl AWhile {
                     return Ok(Flow::Jump(jump));
                 },
             }
+
+            engine.release(roots);
+            engine.collect();
         }
 
-        Flow::none(engine.new_list(&values))
+        Flow::none(list)
     }
 }
 
 impl AFor {
     fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
-        let mut values = Vec::new();
+        let list = engine.new_list(&[]);
+        let mut values = list.as_list();
         engine.with_scope(|engine| {
-            for element in flow!(self.list.read(engine)).as_list().values().iter().copied() {
-                engine.write_value(&self.element, element);
-                match self.body.eval(engine)? {
+            let iterable = flow!(self.list.read(engine));
+            let elements: Box<dyn Iterator<Item = Value<'a>>> = match iterable.data {
+                Data::Range(range) => {
+                    let int = engine.env.int;
+                    Box::new(range.iter().map(move |element| Value::new(int, Data::Int(element))))
+                },
+                Data::List(_) | Data::Map(_) => {
+                    let elements = iterable.as_elements();
+                    for element in elements.iter().copied() {
+                        engine.root(element);
+                    }
+
+                    Box::new(elements.into_vec().into_iter())
+                },
+                _ => return error_type(self.list.pos(), iterable, engine.env.list_any),
+            };
+
+            let roots = engine.roots();
+            for element in elements {
+                let flow = engine.with_scope(|engine| {
+                    engine.write_value(&self.element, element);
+                    self.body.eval(engine)
+                });
+
+                match flow? {
                     Flow::None(value) => {
-                        values.push(value)
+                        values.append(engine.env.any, value);
                     },
                     Flow::Jump(Jump { jump: JumpKind::Continue, value, .. }) => {
                         if let Some(value) = value {
-                            values.push(value);
+                            values.append(engine.env.any, value);
                         }
-
-                        continue;
                     },
                     Flow::Jump(Jump { jump: JumpKind::Break, value, .. }) => {
                         if let Some(value) = value {
-                            values.push(value);
+                            values.append(engine.env.any, value);
                         }
 
                         break;
@@ -426,18 +541,86 @@ impl AFor {
                         return Ok(Flow::Jump(jump));
                     },
                 }
+
+                engine.release(roots);
+                engine.collect();
             }
 
-            Flow::none(engine.new_list(&values))
+            Flow::none(list)
         })
     }
 }
 
+impl ATry {
+    fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
+        let mut result = engine.guard(|engine| self.body.eval(engine));
+        if let Some(catch) = self.catch.as_ref() {
+            if let Err(error) = result {
+                let exception = engine.catch(error)?;
+                result = engine.guard(|engine| catch.eval(engine, exception));
+            }
+        }
+
+        if let Some(finally) = self.finally.as_ref() {
+            flow!(engine.with_thrown(|engine| finally.eval(engine)));
+        }
+
+        result
+    }
+}
+
+impl ACatch {
+    fn eval<'a>(&self, engine: &mut Engine<'a>, exception: Value<'a>) -> ResFlow<'a> {
+        engine.with_scope(|engine| {
+            engine.write_value(&self.name, exception);
+            self.body.eval(engine)
+        })
+    }
+}
+
+impl AFunction {
+    fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
+        Flow::none(make_function(self, engine)?)
+    }
+}
+
 impl AExprAssign {
     fn eval<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
-        let value = flow!(self.right.read(engine));
+        let Some(op) = self.op.as_ref() else {
+            let value = flow!(self.right.read(engine));
+            let mut r#ref = flow!(self.left.read_ref(engine));
+            r#ref.write(self.pos, value)?;
+            return Flow::none(value);
+        };
+
         let mut r#ref = flow!(self.left.read_ref(engine));
+        let left = r#ref.read(self.left.pos())?;
+        engine.root(left);
+        let value = match op.as_ref() {
+            "&&" | "||" => {
+                left.isa_type(self.left.pos(), engine.env.bool)?;
+                if left.as_bool() == (op.as_ref() == "||") {
+                    return Flow::none(left);
+                }
+
+                let right = flow!(self.right.read(engine));
+                right.isa_type(self.right.pos(), engine.env.bool)?;
+                right
+            },
+            _ => {
+                let right = flow!(self.right.read(engine));
+                let name = binop_method(op);
+                let inplace = format!("__i{}", &name[2..]);
+                if left.class.get_method(&inplace).is_some() {
+                    left.call_method(engine, self.pos, &inplace, &[right])?
+                } else {
+                    left.call_method(engine, self.pos, name, &[right])?
+                }
+            },
+        };
+
         r#ref.write(self.pos, value)?;
+        value.store();
         Flow::none(value)
     }
 }
@@ -4,6 +4,10 @@ mod stmt;
 mod r#type;
 pub mod errors;
 
+pub(crate) use def::{make_class_methods, make_function_chunk};
+pub(crate) use expr::{binop_method, parse_int, preop_method, unescape};
+pub(crate) use r#type::read_type_any;
+
 use crate::ast::Pos;
 use crate::runtime::{Engine, Frame, Value};
 use crate::runtime::data::{GcFunction, FunctionBody, GcGeneric, GenericBody, GcClass};
@@ -16,7 +20,8 @@ impl<'a> GcFunction<'a> {
     pub fn call(self, engine: &mut Engine<'a>, pos: Pos, args: &[Value<'a>]) -> ResValue<'a> {
         self.check_args(pos, args)?;
         self.check_rest(pos, args)?;
-        engine.with_frame(Frame::new_function(pos, self), |engine| {
+        let roots = engine.roots();
+        let value = engine.with_frame(Frame::new_function(pos, self), |engine| {
             match self.body {
                 FunctionBody::Block(block) => {
                     self.write_args(engine, args)?;
@@ -24,11 +29,25 @@ impl<'a> GcFunction<'a> {
                     let flow = block.eval(engine)?;
                     self.get_return_value(engine, flow)
                 },
+                FunctionBody::Chunk(chunk) => {
+                    engine.scope.init_slots(chunk.slots());
+                    self.write_slots(engine, pos, args)?;
+                    let flow = chunk.run(engine)?;
+                    self.get_return_value(engine, flow)
+                },
                 FunctionBody::Primitive(primitive) => {
                     primitive(engine, args)
                 },
             }
-        })
+        })?;
+
+        engine.release(roots);
+        engine.root(value);
+        if !matches!(self.body, FunctionBody::Primitive(_)) {
+            engine.collect();
+        }
+
+        Ok(value)
     }
 
     fn check_args(self, pos: Pos, args: &[Value<'a>]) -> Res<()> {
@@ -75,9 +94,22 @@ impl<'a> GcFunction<'a> {
     fn write_rest(self, engine: &mut Engine<'a>, pos: Pos, args: &[Value<'a>]) -> Res<()> {
         if let Some(rest) = self.rest.as_ref() {
             let elements = &args[self.params.len()..];
-            let value = engine.new_list(elements);
-            let class = engine.get_generic(pos, engine.env.list, Box::from([rest.r#type]))?.as_class();
-            engine.write(&rest.name, class, value);
+            let value = engine.new_list_of(pos, rest.r#type, elements)?;
+            engine.write(&rest.name, value.class, value);
+        }
+
+        Ok(())
+    }
+
+    fn write_slots(self, engine: &mut Engine<'a>, pos: Pos, args: &[Value<'a>]) -> Res<()> {
+        for (index, (param, arg)) in zip(self.params.iter(), args.iter().copied()).enumerate() {
+            engine.scope.set_slot(index, param.r#type, arg);
+        }
+
+        if let Some(rest) = self.rest.as_ref() {
+            let elements = &args[self.params.len()..];
+            let value = engine.new_list_of(pos, rest.r#type, elements)?;
+            engine.scope.set_slot(self.params.len(), value.class, value);
         }
 
         Ok(())
@@ -102,6 +134,48 @@ impl<'a> GcFunction<'a> {
     }
 }
 
+impl<'a> GcClass<'a> {
+    pub fn instantiate(self, engine: &mut Engine<'a>, pos: Pos, args: &[Value<'a>]) -> ResValue<'a> {
+        if let Some(init) = self.get_static("__init__") {
+            return init.as_function().call(engine, pos, args);
+        }
+
+        let object = engine.new_object(self);
+        self.write_defaults(engine, object)?;
+        if self.get_method("init").is_some() {
+            object.call_method(engine, pos, "init", args)?;
+        } else if !args.is_empty() {
+            return error_class_init_arity(pos, self, args);
+        }
+
+        Ok(object)
+    }
+
+    fn write_defaults(self, engine: &mut Engine<'a>, object: Value<'a>) -> Res<()> {
+        if let Some(parent) = self.parent() {
+            parent.write_defaults(engine, object)?;
+        }
+
+        for attribute in self.attributes() {
+            if let Some(default) = attribute.default {
+                let scope = engine.scope;
+                engine.root_scope(scope);
+                engine.scope = attribute.scope;
+                let flow = default.read(engine);
+                engine.scope = scope;
+                let value = match flow? {
+                    Flow::None(value) => value,
+                    Flow::Jump(jump) => return error_jump(jump),
+                };
+
+                object.as_object().get_attr(&attribute.name, attribute.r#type).write(default.pos(), value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl<'a> GcGeneric<'a> {
     pub fn apply(self, engine: &mut Engine<'a>, pos: Pos, args: &[GcClass<'a>]) -> ResValue<'a> {
         self.check_args(pos, args)?;
@@ -149,7 +223,9 @@ impl<'a> Value<'a> {
     }
 
     pub fn call_method_self(self, engine: &mut Engine<'a>, pos: Pos, name: &str, args: &[Value<'a>]) -> ResValue<'a> {
-        let method = self.class.get_method(name).unwrap().as_function();
-        method.call(engine, pos, &args)
+        match self.class.get_method(name) {
+            Some(method) => method.as_function().call(engine, pos, args),
+            None => error_member(pos, self.class, name),
+        }
     }
 }
@@ -1,6 +1,8 @@
 use crate::ast::nodes::*;
 use crate::runtime::engine::Engine;
 use crate::runtime::flow::{Jump, ResJump};
+use super::errors::error_export;
+use super::expr::unescape;
 
 macro_rules! flow_jump {
     ( $flow:expr ) => {{
@@ -20,10 +22,47 @@ impl AStmt {
         match self {
             AStmt::Def(def) => def.eval_stmt(engine),
             AStmt::Expr(expr) => expr.eval_stmt(engine),
+            AStmt::Import(import) => import.eval_stmt(engine),
+            AStmt::Export(export) => export.eval_stmt(engine),
         }
     }
 }
 
+impl AImport {
+    fn eval_stmt<'a>(&self, engine: &mut Engine<'a>) -> ResJump<'a> {
+        let mut module = engine.import(self.pos, &unescape(&self.path))?;
+        if let Some(alias) = self.alias.as_ref() {
+            let value = engine.new_module(module);
+            engine.write_value(alias, value);
+        }
+
+        for name in self.names.iter() {
+            let Some(r#ref) = module.get_export(name) else {
+                return error_export(self.pos, &module.name, name);
+            };
+
+            let value = r#ref.read(self.pos)?;
+            engine.write_value(name, value);
+        }
+
+        Jump::none()
+    }
+}
+
+impl AExport {
+    fn eval_stmt<'a>(&self, engine: &mut Engine<'a>) -> ResJump<'a> {
+        if let Some(def) = self.def.as_ref() {
+            def.eval_def(engine)?;
+        }
+
+        for name in self.names.iter() {
+            engine.export(name);
+        }
+
+        Jump::none()
+    }
+}
+
 impl ADef {
     fn eval_stmt<'a>(&self, engine: &mut Engine<'a>) -> ResJump<'a> {
         self.eval_def(engine)?;
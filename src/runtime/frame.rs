@@ -2,7 +2,7 @@ use crate::ast::Pos;
 use crate::runtime::gc::GcTrace;
 use crate::runtime::scope::GcScope;
 
-use super::data::{GcClass, GcFunction, GcGeneric};
+use super::data::{GcClass, GcFunction, GcGeneric, GcModule};
 
 pub struct Frame<'a> {
     pos: Pos,
@@ -11,6 +11,7 @@ pub struct Frame<'a> {
 
 enum FrameBody<'a> {
     Main(FrameMain<'a>),
+    Module(FrameModule<'a>),
     Function(FrameFunction<'a>),
     Generic(FrameGeneric<'a>),
 }
@@ -19,6 +20,10 @@ pub struct FrameMain<'a> {
     scope: GcScope<'a>,
 }
 
+pub struct FrameModule<'a> {
+    module: GcModule<'a>,
+}
+
 pub struct FrameFunction<'a> {
     function: GcFunction<'a>,
 }
@@ -36,6 +41,13 @@ impl<'a> Frame<'a> {
         }
     }
 
+    pub fn new_module(pos: Pos, module: GcModule<'a>) -> Self {
+        Self {
+            pos,
+            body: FrameBody::Module(FrameModule { module }),
+        }
+    }
+
     pub fn new_function(pos: Pos, function: GcFunction<'a>) -> Self {
         Self {
             pos,
@@ -53,6 +65,7 @@ impl<'a> Frame<'a> {
     pub fn name(&self) -> &str {
         match &self.body {
             FrameBody::Main     (_)    => "main",
+            FrameBody::Module   (body) => body.module.name.as_ref(),
             FrameBody::Function (body) => body.function.name.as_ref(),
             FrameBody::Generic  (body) => body.generic.name.as_ref(),
         }
@@ -65,11 +78,19 @@ impl<'a> Frame<'a> {
     pub fn scope(&self) -> GcScope<'a> {
         match &self.body {
             FrameBody::Main     (body) => body.scope,
+            FrameBody::Module   (body) => body.module.scope,
             FrameBody::Function (body) => body.function.scope,
             FrameBody::Generic  (body) => body.generic.scope,
         }
     }
 
+    pub fn function(&self) -> Option<GcFunction<'a>> {
+        match &self.body {
+            FrameBody::Function(body) => Some(body.function),
+            _ => None,
+        }
+    }
+
     pub fn generics(&self) -> &[GcClass<'a>] {
         match &self.body {
             FrameBody::Generic(body) => body.args.as_ref(),
@@ -84,6 +105,9 @@ impl GcTrace for Frame<'_> {
             FrameBody::Main(body) => {
                 body.scope.trace();
             },
+            FrameBody::Module(body) => {
+                body.module.trace();
+            },
             FrameBody::Function(body) => {
                 body.function.trace();
             },
@@ -1,45 +1,130 @@
-use crate::runtime::gc::GcTrace;
+use crate::memory::Mut;
+use crate::runtime::gc::{ GcSite, GcTrace };
+use crate::runtime::gc::marker::GcMarker;
 
 use std::mem::transmute;
 use std::ptr::{ from_raw_parts_mut, DynMetadata };
 
 pub struct GcGuard {
     flag: bool,
+    old: bool,
+    remembered: bool,
+    poisoned: bool,
+    bytes: usize,
+    marker: Mut<GcMarker>,
     object: *mut (),
     metadata: *const (),
+    site: Option<Box<GcSite>>,
 }
 
 impl GcGuard {
-    pub fn new<T: GcTrace>(object: T) -> Self {
+    pub fn new<T: GcTrace>(object: T, marker: Mut<GcMarker>, bytes: usize) -> Self {
         unsafe {
             let pointer: *mut dyn GcTrace = Box::into_raw(Box::new(object));
             let (object, metadata) = pointer.to_raw_parts();
             Self {
                 flag: false,
+                old: false,
+                remembered: false,
+                poisoned: false,
+                bytes,
+                marker,
                 object,
                 metadata: transmute::<DynMetadata<dyn GcTrace>, *const ()>(metadata),
+                site: None,
             }
         }
     }
 
+    pub fn bytes(&mut self) -> usize {
+        self.bytes + self.object().size()
+    }
+
     pub fn reset(&mut self) -> bool {
         let flag = self.flag;
         self.flag = false;
-        !flag
+        flag
+    }
+
+    pub fn locate(&mut self, site: GcSite) {
+        self.site = Some(Box::new(site));
+    }
+
+    pub fn poison(&mut self) {
+        unsafe {
+            drop(Box::<dyn GcTrace>::from_raw(self.object()));
+        }
+
+        self.poisoned = true;
+    }
+
+    pub fn promote(&mut self) {
+        self.old = true;
+    }
+
+    pub fn forget(&mut self) {
+        self.remembered = false;
+    }
+
+    pub fn touch(&mut self) {
+        if self.old {
+            self.remember();
+        }
+    }
+
+    pub fn store(&mut self) {
+        if !self.old {
+            self.remember();
+        }
+    }
+
+    pub fn scan(&mut self) {
+        self.object().trace();
+    }
+
+    pub fn rescan(&mut self) {
+        if self.old {
+            self.object().rescan();
+        } else {
+            self.trace();
+        }
     }
 
     pub fn cast_ref<T>(&self) -> &T {
+        if self.poisoned {
+            self.dangling();
+        }
+
         unsafe {
             transmute::<*mut (), &T>(self.object)
         }
     }
 
     pub fn cast_mut<T>(&mut self) -> &mut T {
+        if self.poisoned {
+            self.dangling();
+        }
+
         unsafe {
             transmute::<*mut (), &mut T>(self.object)
         }
     }
 
+    fn dangling(&self) -> ! {
+        match &self.site {
+            Some(site) => panic!("gc: dangling reference to a {}", site.print()),
+            None => panic!("gc: dangling reference to a freed object"),
+        }
+    }
+
+    fn remember(&mut self) {
+        if !self.remembered {
+            self.remembered = true;
+            let guard = Mut::new(self as *mut GcGuard);
+            self.marker.remembered.push(guard);
+        }
+    }
+
     fn object(&mut self) -> &mut dyn GcTrace {
         unsafe {
             let metadata = transmute::<*const (), DynMetadata<dyn GcTrace>>(self.metadata);
@@ -50,17 +135,30 @@ impl GcGuard {
 
 impl GcTrace for GcGuard {
     fn trace(&mut self) {
-        if !self.flag {
+        if self.poisoned {
+            self.dangling();
+        }
+
+        if self.marker.verifying {
+            if !self.old && !self.flag {
+                self.marker.violations += 1;
+            }
+
+            return;
+        }
+
+        if !self.flag && self.marker.marking && !(self.old && self.marker.minor) {
             self.flag = true;
-            self.object().trace();
+            let guard = Mut::new(self as *mut GcGuard);
+            self.marker.gray.push(guard);
         }
     }
 }
 
 impl Drop for GcGuard {
     fn drop(&mut self) {
-        unsafe {
-            drop(Box::<dyn GcTrace>::from_raw(self.object()));
+        if !self.poisoned {
+            self.poison();
         }
     }
 }
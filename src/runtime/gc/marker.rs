@@ -0,0 +1,35 @@
+use crate::memory::Mut;
+use crate::runtime::gc::guard::GcGuard;
+
+pub struct GcMarker {
+    pub marking: bool,
+    pub minor: bool,
+    pub verifying: bool,
+    pub violations: usize,
+    pub gray: Vec<Mut<GcGuard>>,
+    pub remembered: Vec<Mut<GcGuard>>,
+}
+
+impl GcMarker {
+    pub fn new() -> Self {
+        Self {
+            marking: false,
+            minor: false,
+            verifying: false,
+            violations: 0,
+            gray: Vec::new(),
+            remembered: Vec::new(),
+        }
+    }
+
+    pub fn mark(&mut self, budget: usize) -> bool {
+        for _ in 0 .. budget {
+            match self.gray.pop() {
+                Some(mut guard) => guard.scan(),
+                None => return true,
+            }
+        }
+
+        self.gray.is_empty()
+    }
+}
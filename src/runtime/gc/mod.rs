@@ -1,47 +1,247 @@
-mod cache;
+//! Precise mark-and-sweep garbage collector.
+//!
+//! Every object is allocated behind a guard that holds its mark. Values only
+//! held by the interpreter itself, such as the temporaries of an expression
+//! being evaluated, are registered in the root stack until the statement that
+//! produced them completes. A collection starts once the heap has grown past
+//! its threshold, and only runs at safepoints: statement boundaries, loop
+//! iterations and function returns.
+//!
+//! Marking pushes the roots on a gray stack and scans it until it is empty.
+//! In incremental mode, each safepoint only scans a slice of the gray stack,
+//! and the write barrier shades values stored into objects in the meantime so
+//! that they cannot be missed. The roots are traced again before sweeping.
+//!
+//! Newly allocated objects start in a young generation. Whenever it outgrows
+//! the nursery, a minor collection marks and sweeps it without tracing the
+//! old generation, and promotes the survivors. Old objects mutated since the
+//! last collection are kept in a remembered set and rescanned by the minor
+//! collection, so that the young objects they reference are found.
+//!
+//! In verify mode, a collection runs at every safepoint. After each minor
+//! collection, every young object referenced from the old generation must
+//! have been marked. Freed objects are poisoned and kept in a graveyard
+//! instead of being released, so that any later access through a dangling
+//! reference panics with the type of the object and the function and position
+//! that allocated it.
+
 mod guard;
+mod marker;
 mod r#ref;
+mod roots;
+mod site;
 mod trace;
 
-pub use cache::GcCache;
 pub use r#ref::GcRef;
+pub use roots::GcRoots;
+pub use site::GcSite;
 pub use trace::GcTrace;
 
 use crate::memory::Own;
 use guard::GcGuard;
+use marker::GcMarker;
+
+use std::mem::{ size_of, take };
 
 #[cfg(test)]
 pub const GC_THRESHOLD: usize = 0;
 #[cfg(not(test))]
-pub const GC_THRESHOLD: usize = 1000;
+pub const GC_THRESHOLD: usize = 1 << 20;
+
+pub const GC_GROWTH: usize = 2;
+
+#[cfg(test)]
+pub const GC_NURSERY: usize = 0;
+#[cfg(not(test))]
+pub const GC_NURSERY: usize = 1 << 18;
+
+#[cfg(test)]
+pub const GC_SLICE: usize = 16;
+#[cfg(not(test))]
+pub const GC_SLICE: usize = 1000;
 
 pub struct Gc {
-    guards: Vec<Own<GcGuard>>,
-    allocations: usize,
+    pub incremental: bool,
+    pub verify: bool,
+    old: Vec<Own<GcGuard>>,
+    young: Vec<Own<GcGuard>>,
+    graveyard: Vec<Own<GcGuard>>,
+    marker: Own<GcMarker>,
+    roots: GcRoots,
+    bytes: usize,
+    nursery: usize,
+    threshold: usize,
+    stats: GcStats,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct GcStats {
+    pub collections: usize,
+    pub minor: usize,
+    pub freed: usize,
 }
 
 impl Gc {
     pub fn new() -> Self {
         Self {
-            guards: Vec::new(),
-            allocations: 0,
+            incremental: false,
+            verify: false,
+            old: Vec::new(),
+            young: Vec::new(),
+            graveyard: Vec::new(),
+            marker: Own::new(GcMarker::new()),
+            roots: GcRoots::new(),
+            bytes: 0,
+            nursery: 0,
+            threshold: 0,
+            stats: GcStats::default(),
         }
     }
 
     pub fn alloc<T: GcTrace>(&mut self, object: T) -> GcRef<T> {
-        let mut guard = Own::new(GcGuard::new(object));
-        let r#ref = GcRef::new(guard.get_mut());
-        self.guards.push(guard);
-        self.allocations += 1;
+        let bytes = size_of::<GcGuard>() + size_of::<T>();
+        self.bytes += bytes + object.size();
+        self.nursery += bytes + object.size();
+        let mut guard = Own::new(GcGuard::new(object, self.marker.get_mut(), bytes));
+        let mut r#ref = GcRef::<T>::new(guard.get_mut());
+        r#ref.trace();
+        self.young.push(guard);
+        self.roots.push(r#ref);
         r#ref
     }
 
-    pub fn collect(&mut self) {
-        self.guards.drain_filter(|guard| guard.reset());
-        self.allocations = 0;
+    pub fn roots(&mut self) -> &mut GcRoots {
+        &mut self.roots
+    }
+
+    pub fn marking(&self) -> bool {
+        self.marker.marking
+    }
+
+    pub fn pressure(&self) -> bool {
+        self.bytes > self.threshold && (self.verify || self.bytes > GC_THRESHOLD)
     }
 
-    pub fn allocations(&self) -> usize {
-        self.allocations
+    pub fn nursery(&self) -> bool {
+        self.nursery > GC_NURSERY || self.verify && self.nursery > 0
+    }
+
+    pub fn begin(&mut self) {
+        self.marker.marking = true;
+    }
+
+    pub fn begin_minor(&mut self) {
+        self.marker.marking = true;
+        self.marker.minor = true;
+        for index in 0 .. self.marker.remembered.len() {
+            let mut guard = self.marker.remembered[index];
+            guard.rescan();
+        }
+    }
+
+    pub fn mark(&mut self, budget: usize) -> bool {
+        self.marker.mark(budget)
+    }
+
+    pub fn sweep(&mut self) {
+        self.forget();
+        let (freed, bytes) = Self::sweep_guards(&mut self.old, &mut self.graveyard, self.verify);
+        let (young_freed, young_bytes) = Self::sweep_guards(&mut self.young, &mut self.graveyard, self.verify);
+        self.promote();
+
+        self.bytes = bytes + young_bytes;
+        self.threshold = self.bytes * GC_GROWTH;
+        self.stats.collections += 1;
+        self.stats.freed += freed + young_freed;
+    }
+
+    pub fn sweep_minor(&mut self) {
+        if self.verify {
+            self.check();
+        }
+
+        self.forget();
+        let (freed, bytes) = Self::sweep_guards(&mut self.young, &mut self.graveyard, self.verify);
+        self.promote();
+
+        self.bytes = self.bytes.saturating_sub(self.nursery) + bytes;
+        self.stats.collections += 1;
+        self.stats.minor += 1;
+        self.stats.freed += freed;
+    }
+
+    pub fn objects(&self) -> usize {
+        self.old.len() + self.young.len()
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    pub fn stats(&self) -> GcStats {
+        self.stats
+    }
+
+    fn sweep_guards(guards: &mut Vec<Own<GcGuard>>, graveyard: &mut Vec<Own<GcGuard>>, verify: bool) -> (usize, usize) {
+        let mut freed = 0;
+        let mut bytes = 0;
+        let dead = guards.extract_if(.., |guard| {
+            let alive = guard.reset();
+            if alive {
+                bytes += guard.bytes();
+            }
+
+            !alive
+        });
+
+        for mut guard in dead {
+            freed += 1;
+            if verify {
+                guard.poison();
+                graveyard.push(guard);
+            }
+        }
+
+        (freed, bytes)
+    }
+
+    fn promote(&mut self) {
+        for guard in self.young.iter_mut() {
+            guard.promote();
+        }
+
+        self.old.append(&mut self.young);
+        self.nursery = 0;
+        self.marker.marking = false;
+        self.marker.minor = false;
+    }
+
+    fn forget(&mut self) {
+        for mut guard in self.marker.remembered.drain(..) {
+            guard.forget();
+        }
+    }
+
+    fn check(&mut self) {
+        self.marker.verifying = true;
+        for guard in self.old.iter_mut() {
+            guard.scan();
+        }
+
+        self.marker.verifying = false;
+        let violations = take(&mut self.marker.violations);
+        if violations > 0 {
+            panic!("gc: {} young objects referenced from old objects were not marked", violations);
+        }
+    }
+}
+
+pub fn barrier<T: GcTrace>(mut object: T) {
+    object.trace();
+}
+
+impl GcTrace for Gc {
+    fn trace(&mut self) {
+        self.roots.trace();
     }
 }
@@ -1,6 +1,9 @@
+use crate::ast::Pos;
 use crate::memory::Mut;
-use crate::runtime::gc::{ GcGuard, GcTrace };
+use crate::runtime::gc::{ GcGuard, GcSite, GcTrace };
 
+use std::any::type_name;
+use std::hash::{ Hash, Hasher };
 use std::marker::PhantomData;
 use std::ops::{ Deref, DerefMut };
 
@@ -27,6 +30,18 @@ impl<T: GcTrace> GcRef<T> {
     pub fn anonymize(self) -> GcRef<()> {
         GcRef::new(self.guard)
     }
+
+    pub fn cast<U: GcTrace>(self) -> GcRef<U> {
+        GcRef::new(self.guard)
+    }
+
+    pub fn store(mut self) {
+        self.guard.store();
+    }
+
+    pub fn locate(mut self, frame: Option<(&str, Pos)>) {
+        self.guard.locate(GcSite::new(type_name::<T>(), frame));
+    }
 }
 
 impl<T: GcTrace> GcTrace for GcRef<T> {
@@ -45,6 +60,7 @@ impl<T: GcTrace> Deref for GcRef<T> {
 
 impl<T: GcTrace> DerefMut for GcRef<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard.touch();
         self.guard.cast_mut::<T>()
     }
 }
@@ -57,6 +73,12 @@ impl<T: GcTrace> PartialEq for GcRef<T> {
 
 impl<T: GcTrace> Eq for GcRef<T> {}
 
+impl<T: GcTrace> Hash for GcRef<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.guard.hash(state);
+    }
+}
+
 impl<T: GcTrace> Clone for GcRef<T> {
     fn clone(&self) -> Self {
         Self {
@@ -0,0 +1,54 @@
+use crate::memory::Mut;
+use crate::runtime::gc::{ GcRef, GcTrace };
+
+use std::mem::transmute;
+
+pub struct GcRoots {
+    stack: Vec<GcRef<()>>,
+    natives: Vec<Mut<dyn GcTrace>>,
+}
+
+impl GcRoots {
+    pub fn new() -> Self {
+        Self {
+            stack: Vec::new(),
+            natives: Vec::new(),
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.stack.len()
+    }
+
+    pub fn push<T: GcTrace>(&mut self, r#ref: GcRef<T>) {
+        self.stack.push(r#ref.anonymize());
+    }
+
+    pub fn truncate(&mut self, height: usize) {
+        self.stack.truncate(height);
+    }
+
+    pub fn register(&mut self, roots: &mut dyn GcTrace) {
+        let roots = unsafe {
+            transmute::<*mut (dyn GcTrace + '_), *mut (dyn GcTrace + 'static)>(roots)
+        };
+
+        self.natives.push(Mut::new(roots));
+    }
+
+    pub fn unregister(&mut self) {
+        self.natives.pop();
+    }
+}
+
+impl GcTrace for GcRoots {
+    fn trace(&mut self) {
+        for r#ref in self.stack.iter_mut() {
+            r#ref.trace();
+        }
+
+        for native in self.natives.iter_mut() {
+            native.trace();
+        }
+    }
+}
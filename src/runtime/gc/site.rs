@@ -0,0 +1,22 @@
+use crate::ast::Pos;
+
+pub struct GcSite {
+    r#type: &'static str,
+    frame: Option<(Box<str>, Pos)>,
+}
+
+impl GcSite {
+    pub fn new(r#type: &'static str, frame: Option<(&str, Pos)>) -> Self {
+        Self {
+            r#type: r#type.rsplit("::").next().unwrap(),
+            frame: frame.map(|(name, pos)| (Box::from(name), pos)),
+        }
+    }
+
+    pub fn print(&self) -> String {
+        match &self.frame {
+            Some((name, pos)) => format!("{} allocated in `{}` {} of `{}`", self.r#type, name, pos.print_pos(), pos.print_name()),
+            None => format!("{} allocated before the program started", self.r#type),
+        }
+    }
+}
@@ -1,5 +1,13 @@
 pub trait GcTrace {
     fn trace(&mut self) {}
+
+    fn rescan(&mut self) {
+        self.trace();
+    }
+
+    fn size(&self) -> usize {
+        0
+    }
 }
 
 impl GcTrace for () {}
@@ -1,5 +1,6 @@
 use crate::runtime::Value;
 use crate::runtime::data::{GcClass, GcGeneric};
+use crate::runtime::gc::GcTrace;
 
 pub struct Generics<'a> {
     entries: Vec<(GcGeneric<'a>, Box<[GcClass<'a>]>, Value<'a>)>,
@@ -20,3 +21,16 @@ impl<'a> Generics<'a> {
         self.entries.push((generic, args, value));
     }
 }
+
+impl GcTrace for Generics<'_> {
+    fn trace(&mut self) {
+        for (generic, args, value) in self.entries.iter_mut() {
+            generic.trace();
+            for arg in args.iter_mut() {
+                arg.trace();
+            }
+
+            value.trace();
+        }
+    }
+}
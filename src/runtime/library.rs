@@ -0,0 +1,11 @@
+use std::path::Path;
+
+const MODULES: [(&str, &str); 3] = [
+    ("std/collections.lif", include_str!("../../std/collections.lif")),
+    ("std/strings.lif",     include_str!("../../std/strings.lif")),
+    ("std/testing.lif",     include_str!("../../std/testing.lif")),
+];
+
+pub fn find(path: &Path) -> Option<(&'static str, &'static str)> {
+    MODULES.iter().copied().find(|(name, _)| Path::new(name) == path)
+}
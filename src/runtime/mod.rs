@@ -6,10 +6,12 @@ pub mod eval;
 pub mod flow;
 pub mod gc;
 pub mod generics;
+pub mod library;
 pub mod primitive;
 pub mod scope;
 pub mod value;
 pub mod variable;
+pub mod vm;
 pub mod frame;
 
 pub use engine::Engine;
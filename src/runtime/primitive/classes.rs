@@ -17,19 +17,27 @@ impl PrimClass {
     }
 }
 
-pub fn get_classes() -> [PrimClass; 12] {
+pub fn get_classes() -> [PrimClass; 20] {
     [
-        PrimClass::new("Any",      |_|   None,          |env| &mut env.any),
-        PrimClass::new("Bool",     |env| Some(env.any), |env| &mut env.bool),
-        PrimClass::new("Class",    |env| Some(env.any), |env| &mut env.class),
-        PrimClass::new("Float",    |env| Some(env.any), |env| &mut env.float),
-        PrimClass::new("Function", |env| Some(env.any), |env| &mut env.function),
-        PrimClass::new("Generic",  |env| Some(env.any), |env| &mut env.generic),
-        PrimClass::new("Int",      |env| Some(env.any), |env| &mut env.int),
-        PrimClass::new("Method",   |env| Some(env.any), |env| &mut env.method),
-        PrimClass::new("Object",   |env| Some(env.any), |env| &mut env.object),
-        PrimClass::new("Ref",      |env| Some(env.any), |env| &mut env.r#ref),
-        PrimClass::new("String",   |env| Some(env.any), |env| &mut env.string),
-        PrimClass::new("Void",     |env| Some(env.any), |env| &mut env.void),
+        PrimClass::new("Any",           |_|   None,             |env| &mut env.any),
+        PrimClass::new("BigInt",        |env| Some(env.any),    |env| &mut env.bigint),
+        PrimClass::new("Bool",          |env| Some(env.any),    |env| &mut env.bool),
+        PrimClass::new("Class",         |env| Some(env.any),    |env| &mut env.class),
+        PrimClass::new("Float",         |env| Some(env.any),    |env| &mut env.float),
+        PrimClass::new("Function",      |env| Some(env.any),    |env| &mut env.function),
+        PrimClass::new("Generic",       |env| Some(env.any),    |env| &mut env.generic),
+        PrimClass::new("Int",           |env| Some(env.any),    |env| &mut env.int),
+        PrimClass::new("Method",        |env| Some(env.any),    |env| &mut env.method),
+        PrimClass::new("Module",        |env| Some(env.any),    |env| &mut env.module),
+        PrimClass::new("Object",        |env| Some(env.any),    |env| &mut env.object),
+        PrimClass::new("Range",         |env| Some(env.any),    |env| &mut env.range),
+        PrimClass::new("Ref",           |env| Some(env.any),    |env| &mut env.r#ref),
+        PrimClass::new("String",        |env| Some(env.any),    |env| &mut env.string),
+        PrimClass::new("Void",          |env| Some(env.any),    |env| &mut env.void),
+        PrimClass::new("Error",         |env| Some(env.object), |env| &mut env.error),
+        PrimClass::new("ArityError",    |env| Some(env.error),  |env| &mut env.error_arity),
+        PrimClass::new("JumpError",     |env| Some(env.error),  |env| &mut env.error_jump),
+        PrimClass::new("TypeError",     |env| Some(env.error),  |env| &mut env.error_type),
+        PrimClass::new("VariableError", |env| Some(env.error),  |env| &mut env.error_variable),
     ]
 }
@@ -1,11 +1,11 @@
-use crate::parser::Code;
+use crate::ast::Pos;
 use crate::runtime::data::{Param, GcClass, Function};
 use crate::runtime::engine::Engine;
 use crate::runtime::env::Env;
 use crate::runtime::value::Value;
 use crate::runtime::flow::ResValue;
 
-use std::{fs, process};
+use std::process;
 
 pub struct PrimFunction<'a> {
     pub name: &'static str,
@@ -77,14 +77,14 @@ impl<'a> PrimFunction<'a> {
 
 pub fn get_functions<'a>(env: &Env<'a>) -> [PrimFunction<'a>; 8] {
     [
-        PrimFunction::new("assert",  [("value", env.bool)],  env.void, assert),
-        PrimFunction::new("error",   [("value", env.any)],   env.void, error),
-        PrimFunction::new("eval",    [("expr", env.string)], env.any,  eval),
-        PrimFunction::new("exec",    [("prog", env.string)], env.void, exec),
-        PrimFunction::new("exit",    [("code", env.int)],    env.any,  exit),
-        PrimFunction::new("include", [("path", env.string)], env.void, include),
-        PrimFunction::new("print",   [("value", env.any)],   env.void, print),
-        PrimFunction::new("new",     [("class", env.class)], env.any,  new),
+        PrimFunction::new("assert",   [("value", env.bool)],  env.void,   assert),
+        PrimFunction::new("error",    [("value", env.any)],   env.void,   error),
+        PrimFunction::new("eval",     [("expr", env.string)], env.any,    eval),
+        PrimFunction::new("exec",     [("prog", env.string)], env.void,   exec),
+        PrimFunction::new("exit",     [("code", env.int)],    env.any,    exit),
+        PrimFunction::new("gc_stats", [],                     env.object, gc_stats),
+        PrimFunction::new("print",    [("value", env.any)],   env.void,   print),
+        PrimFunction::new_rest("new", [("class", env.class)], ("arguments", env.any), env.any, new),
     ]
 }
 
@@ -103,16 +103,11 @@ fn error<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
 }
 
 fn eval<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    let code = Code::new(&engine.grammar, engine.grammar.program, None, Box::from(args[0].as_string().as_ref()));
-    Ok(match engine.run(code) {
-        Some(value) => value,
-        None => engine.new_void(),
-    })
+    engine.eval(Box::from(args[0].as_string().as_ref()))
 }
 
 fn exec<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    let code = Code::new(&engine.grammar, engine.grammar.program, None, Box::from(args[0].as_string().as_ref()));
-    engine.run(code);
+    engine.eval(Box::from(args[0].as_string().as_ref()))?;
     Ok(engine.new_void())
 }
 
@@ -120,16 +115,26 @@ fn exit<'a>(_: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     process::exit(args[0].as_int() as i32);
 }
 
-fn include<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    let name = args[0].as_string();
-    let text = fs::read_to_string(name.as_ref()).unwrap().into_boxed_str();
-    let code = Code::new(&engine.grammar, engine.grammar.program, Some(name.as_ref()), text);
-    engine.run(code);
-    Ok(engine.new_void())
+fn gc_stats<'a>(engine: &mut Engine<'a>, _: &[Value<'a>]) -> ResValue<'a> {
+    let stats = engine.gc.stats();
+    let value = engine.new_object(engine.env.object);
+    let mut object = value.as_object();
+    for (attr, count) in [
+        ("objects", engine.gc.objects()),
+        ("bytes", engine.gc.bytes()),
+        ("collections", stats.collections),
+        ("minor_collections", stats.minor),
+        ("freed", stats.freed),
+    ] {
+        let count = engine.new_int(count as i64);
+        object.get_attr(attr, engine.env.int).write(Pos::DUMMY, count)?;
+    }
+
+    Ok(value)
 }
 
 fn new<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_object(args[0].as_class()))
+    args[0].as_class().instantiate(engine, engine.frame().pos(), &args[1..])
 }
 
 fn print<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
@@ -29,9 +29,10 @@ impl<'a> PrimGeneric<'a> {
     }
 }
 
-pub fn get_generics<'a>(env: &mut Env<'a>) -> [PrimGeneric<'a>; 1] {
+pub fn get_generics<'a>(env: &mut Env<'a>) -> [PrimGeneric<'a>; 2] {
     [
-        PrimGeneric::new("List", [("T", env.any)], list, |env| &mut env.list),
+        PrimGeneric::new("List", [("T", env.any)],                 list, |env| &mut env.list),
+        PrimGeneric::new("Map",  [("K", env.any), ("V", env.any)], map,  |env| &mut env.map),
     ]
 }
 
@@ -51,3 +52,20 @@ fn list<'a>(engine: &mut Engine<'a>, args: &[GcClass<'a>]) -> ResValue<'a> {
 
     Ok(engine.new_class_primitive(class))
 }
+
+fn map<'a>(engine: &mut Engine<'a>, args: &[GcClass<'a>]) -> ResValue<'a> {
+    let mut class = engine.alloc(Class::new("Map", Some(engine.env.any), Box::from(args), HashMap::new()));
+    let prim_stats = statics::get_map_statics(&engine.env, class);
+    for prim_stat in prim_stats {
+        let function = prim_stat.to_function(engine);
+        class.add_static(prim_stat.name, engine.new_function(function));
+    }
+
+    let prim_mets = methods::get_map_methods(&engine.env, class, args);
+    for prim_met in prim_mets {
+        let function = prim_met.to_method(engine, class);
+        class.add_method(prim_met.name, engine.new_function(function));
+    }
+
+    Ok(engine.new_class_primitive(class))
+}
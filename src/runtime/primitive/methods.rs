@@ -1,11 +1,17 @@
-use crate::runtime::data::GcClass;
+use crate::runtime::data::{BigInt, Data, List, Range, GcClass};
 use crate::runtime::engine::Engine;
 use crate::runtime::env::Env;
+use crate::runtime::eval::errors::{
+    error_convert, error_division, error_export, error_function_call_arity, error_index, error_key, error_member, error_overflow, error_parse, error_repeat, error_slice,
+    error_step, error_type,
+};
 use crate::runtime::flow::{Res, ResValue};
+use crate::runtime::gc::GcRef;
 use crate::runtime::primitive::functions::PrimFunction;
 use crate::runtime::value::Value;
 
-use std::mem::size_of;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 pub struct PrimMethod<'a> {
     pub class: GcClass<'a>,
@@ -21,93 +27,255 @@ impl<'a> PrimMethod<'a> {
     }
 }
 
-pub fn get_methods<'a>(env: &Env<'a>) -> [PrimMethod<'a>; 9] {
+pub fn get_methods<'a>(env: &Env<'a>) -> [PrimMethod<'a>; 13] {
     [
         PrimMethod::new(env.any, [
-            PrimFunction::new("__str__", [], env.any,  any_str),
-            PrimFunction::new("__cn__",  [("other", env.string)], env.any,  any_cn),
-            PrimFunction::new("__ne__",  [("other", env.any)],    env.bool, any_ne),
-            PrimFunction::new("__gt__",  [("other", env.any)],    env.bool, any_gt),
-            PrimFunction::new("__le__",  [("other", env.any)],    env.bool, any_le),
-            PrimFunction::new("__ge__",  [("other", env.any)],    env.bool, any_ge),
+            PrimFunction::new("__str__",  [],                      env.any,  any_str),
+            PrimFunction::new("__hash__", [],                      env.int,  any_hash),
+            PrimFunction::new("__cn__",   [("other", env.string)], env.any,  any_cn),
+            PrimFunction::new("__eq__",   [("other", env.any)],    env.bool, any_eq),
+            PrimFunction::new("__ne__",   [("other", env.any)],    env.bool, any_ne),
+            PrimFunction::new("__gt__",   [("other", env.any)],    env.bool, any_gt),
+            PrimFunction::new("__le__",   [("other", env.any)],    env.bool, any_le),
+            PrimFunction::new("__ge__",   [("other", env.any)],    env.bool, any_ge),
+        ]),
+        PrimMethod::new(env.bigint, [
+            PrimFunction::new("__str__",  [],                   env.string, bigint_str),
+            PrimFunction::new("__hash__", [],                   env.int,    bigint_hash),
+            PrimFunction::new("__eq__",   [("other", env.any)], env.bool,   bigint_eq),
+            PrimFunction::new("__lt__",   [("other", env.any)], env.bool,   bigint_lt),
+            PrimFunction::new("__pos__",  [],                   env.bigint, bigint_pos),
+            PrimFunction::new("__neg__",  [],                   env.bigint, bigint_neg),
+            PrimFunction::new("__add__",  [("other", env.any)], env.any,    bigint_add),
+            PrimFunction::new("__sub__",  [("other", env.any)], env.any,    bigint_sub),
+            PrimFunction::new("__mul__",  [("other", env.any)], env.any,    bigint_mul),
+            PrimFunction::new("__div__",  [("other", env.any)], env.any,    bigint_div),
+            PrimFunction::new("__rem__",  [("other", env.any)], env.any,    bigint_rem),
+            PrimFunction::new("__pow__",  [("other", env.any)], env.any,    bigint_pow),
+            PrimFunction::new("__bnot__", [],                   env.bigint, bigint_bnot),
+            PrimFunction::new("__band__", [("other", env.any)], env.bigint, bigint_band),
+            PrimFunction::new("__bor__",  [("other", env.any)], env.bigint, bigint_bor),
+            PrimFunction::new("__bxor__", [("other", env.any)], env.bigint, bigint_bxor),
+            PrimFunction::new("__bls__",  [("other", env.int)], env.bigint, bigint_bls),
+            PrimFunction::new("__brs__",  [("other", env.int)], env.bigint, bigint_brs),
+            PrimFunction::new("abs",      [],                   env.bigint, bigint_abs),
+            PrimFunction::new("to_float", [],                   env.float,  bigint_to_float),
+            PrimFunction::new("to_int",   [],                   env.int,    bigint_to_int),
         ]),
         PrimMethod::new(env.bool, [
-            PrimFunction::new("__str__", [],                   env.string, bool_str),
-            PrimFunction::new("__eq__",  [("other", env.any)], env.bool,   bool_eq),
-            PrimFunction::new("__not__", [],                   env.bool,   bool_not),
+            PrimFunction::new("__str__",  [],                   env.string, bool_str),
+            PrimFunction::new("__hash__", [],                   env.int,    bool_hash),
+            PrimFunction::new("__eq__",   [("other", env.any)], env.bool,   bool_eq),
+            PrimFunction::new("__not__",  [],                   env.bool,   bool_not),
         ]),
         PrimMethod::new(env.class, [
-            PrimFunction::new("__cl__",  [("arguments", env.any)], env.any, class_cl),
+            PrimFunction::new("__cl__",  [("arguments", env.any)],  env.any, class_cl),
+            PrimFunction::new("__cn__",  [("member", env.string)], env.any, class_cn),
+        ]),
+        PrimMethod::new(env.error, [
+            PrimFunction::new("__str__", [], env.string, error_str),
         ]),
         PrimMethod::new(env.float, [
-            PrimFunction::new("__str__", [],                      env.string, float_str),
-            PrimFunction::new("__eq__",  [("other", env.any)],    env.bool,   float_eq),
-            PrimFunction::new("__lt__",  [("other", env.float)],  env.bool,   float_lt),
-            PrimFunction::new("__pos__", [],                      env.float,  float_pos),
-            PrimFunction::new("__neg__", [],                      env.float,  float_neg),
-            PrimFunction::new("__add__", [("other", env.float)],  env.float,  float_add),
-            PrimFunction::new("__sub__", [("other", env.float)],  env.float,  float_sub),
-            PrimFunction::new("__mul__", [("other", env.float)],  env.float,  float_mul),
-            PrimFunction::new("__div__", [("other", env.float)],  env.float,  float_div),
-            PrimFunction::new("__rem__", [("other", env.float)],  env.float,  float_rem),
+            PrimFunction::new("__str__",  [],                   env.string, float_str),
+            PrimFunction::new("__hash__", [],                   env.int,    float_hash),
+            PrimFunction::new("__eq__",   [("other", env.any)], env.bool,   float_eq),
+            PrimFunction::new("__lt__",   [("other", env.any)], env.bool,   float_lt),
+            PrimFunction::new("__pos__",  [],                   env.float,  float_pos),
+            PrimFunction::new("__neg__",  [],                   env.float,  float_neg),
+            PrimFunction::new("__add__",  [("other", env.any)], env.float,  float_add),
+            PrimFunction::new("__sub__",  [("other", env.any)], env.float,  float_sub),
+            PrimFunction::new("__mul__",  [("other", env.any)], env.float,  float_mul),
+            PrimFunction::new("__div__",  [("other", env.any)], env.float,  float_div),
+            PrimFunction::new("__rem__",  [("other", env.any)], env.float,  float_rem),
+            PrimFunction::new("__pow__",  [("other", env.any)], env.float,  float_pow),
+            PrimFunction::new("abs",      [],                   env.float,  float_abs),
+            PrimFunction::new("ceil",     [],                   env.float,  float_ceil),
+            PrimFunction::new("floor",    [],                   env.float,  float_floor),
+            PrimFunction::new("round",    [],                   env.float,  float_round),
+            PrimFunction::new("to_float", [],                   env.float,  float_to_float),
+            PrimFunction::new("to_int",   [],                   env.int,    float_to_int),
         ]),
         PrimMethod::new(env.function, [
             PrimFunction::new("__cl__",  [("arguments", env.any)], env.any, function_cl),
         ]),
         PrimMethod::new(env.int, [
-            PrimFunction::new("__str__",  [],                   env.string, int_str),
-            PrimFunction::new("__eq__",   [("other", env.any)], env.bool,   int_eq),
-            PrimFunction::new("__lt__",   [("other", env.int)], env.bool,   int_lt),
-            PrimFunction::new("__pos__",  [],                   env.int,    int_pos),
-            PrimFunction::new("__neg__",  [],                   env.int,    int_neg),
-            PrimFunction::new("__add__",  [("other", env.int)], env.int,    int_add),
-            PrimFunction::new("__sub__",  [("other", env.int)], env.int,    int_sub),
-            PrimFunction::new("__mul__",  [("other", env.int)], env.int,    int_mul),
-            PrimFunction::new("__div__",  [("other", env.int)], env.int,    int_div),
-            PrimFunction::new("__rem__",  [("other", env.int)], env.int,    int_rem),
-            PrimFunction::new("__bnot__", [],                   env.int,    int_bnot),
-            PrimFunction::new("__band__", [("other", env.int)], env.int,    int_band),
-            PrimFunction::new("__bor__",  [("other", env.int)], env.int,    int_bor),
-            PrimFunction::new("__bxor__", [("other", env.int)], env.int,    int_bxor),
-            PrimFunction::new("__bls__",  [("other", env.int)], env.int,    int_bls),
-            PrimFunction::new("__brs__",  [("other", env.int)], env.int,    int_brs),
-            PrimFunction::new("__bcls__", [("other", env.int)], env.int,    int_bcls),
-            PrimFunction::new("__bcrs__", [("other", env.int)], env.int,    int_bcrs),
+            PrimFunction::new("__str__",      [],                   env.string, int_str),
+            PrimFunction::new("__hash__",     [],                   env.int,    int_hash),
+            PrimFunction::new("__eq__",       [("other", env.any)], env.bool,   int_eq),
+            PrimFunction::new("__lt__",       [("other", env.any)], env.bool,   int_lt),
+            PrimFunction::new("__pos__",      [],                   env.int,    int_pos),
+            PrimFunction::new("__neg__",      [],                   env.int,    int_neg),
+            PrimFunction::new("__add__",      [("other", env.any)], env.any,    int_add),
+            PrimFunction::new("__sub__",      [("other", env.any)], env.any,    int_sub),
+            PrimFunction::new("__mul__",      [("other", env.any)], env.any,    int_mul),
+            PrimFunction::new("__div__",      [("other", env.any)], env.any,    int_div),
+            PrimFunction::new("__rem__",      [("other", env.any)], env.any,    int_rem),
+            PrimFunction::new("__pow__",      [("other", env.any)], env.any,    int_pow),
+            PrimFunction::new("__bnot__",     [],                   env.int,    int_bnot),
+            PrimFunction::new("__band__",     [("other", env.int)], env.int,    int_band),
+            PrimFunction::new("__bor__",      [("other", env.int)], env.int,    int_bor),
+            PrimFunction::new("__bxor__",     [("other", env.int)], env.int,    int_bxor),
+            PrimFunction::new("__bls__",      [("other", env.int)], env.int,    int_bls),
+            PrimFunction::new("__brs__",      [("other", env.int)], env.int,    int_brs),
+            PrimFunction::new("__bcls__",     [("other", env.int)], env.int,    int_bcls),
+            PrimFunction::new("__bcrs__",     [("other", env.int)], env.int,    int_bcrs),
+            PrimFunction::new("__range__",    [("other", env.int)], env.range,  int_range),
+            PrimFunction::new("__range_eq__", [("other", env.int)], env.range,  int_range_eq),
+            PrimFunction::new("abs",          [],                   env.int,    int_abs),
+            PrimFunction::new("ceil",         [],                   env.int,    int_to_int),
+            PrimFunction::new("floor",        [],                   env.int,    int_to_int),
+            PrimFunction::new("round",        [],                   env.int,    int_to_int),
+            PrimFunction::new("to_bigint",    [],                   env.bigint, int_to_bigint),
+            PrimFunction::new("to_float",     [],                   env.float,  int_to_float),
+            PrimFunction::new("to_int",       [],                   env.int,    int_to_int),
         ]),
         PrimMethod::new(env.method, [
             PrimFunction::new("__cl__",  [("arguments", env.any)], env.any, method_cl),
         ]),
+        PrimMethod::new(env.module, [
+            PrimFunction::new("__cn__",  [("member", env.string)], env.any, module_cn),
+        ]),
         PrimMethod::new(env.object, [
             PrimFunction::new("__str__", [],                       env.string, object_str),
             PrimFunction::new("__cn__",  [("member", env.string)], env.any,    object_cn),
         ]),
+        PrimMethod::new(env.range, [
+            PrimFunction::new("__str__",  [],                   env.string, range_str),
+            PrimFunction::new("contains", [("value", env.int)], env.bool,   range_contains),
+            PrimFunction::new("len",      [],                   env.int,    range_len),
+            PrimFunction::new("rev",      [],                   env.range,  range_rev),
+            PrimFunction::new("step",     [("step", env.int)],  env.range,  range_step),
+            PrimFunction::new("to_list",  [],                   env.any,    range_to_list),
+        ]),
         PrimMethod::new(env.string, [
-            PrimFunction::new("__str__", [],                      env.string, string_str),
-            PrimFunction::new("__eq__",  [("other", env.any)],    env.string, string_eq),
-            PrimFunction::new("__add__", [("other", env.any)], env.string, string_add),
+            PrimFunction::new("__str__",     [],                                         env.string, string_str),
+            PrimFunction::new("__hash__",    [],                                         env.int,    string_hash),
+            PrimFunction::new("__eq__",      [("other", env.any)],                       env.bool,   string_eq),
+            PrimFunction::new("__lt__",      [("other", env.string)],                    env.bool,   string_lt),
+            PrimFunction::new("__add__",     [("other", env.any)],                       env.string, string_add),
+            PrimFunction::new("__cl__",      [("args", env.any)],                        env.string, string_cl),
+            PrimFunction::new("len",         [],                                         env.int,    string_len),
+            PrimFunction::new("slice",       [("start", env.int), ("end", env.int)],     env.string, string_slice),
+            PrimFunction::new("split",       [("separator", env.string)],                env.any,    string_split),
+            PrimFunction::new("join",        [("list", env.any)],                        env.string, string_join),
+            PrimFunction::new("trim",        [],                                         env.string, string_trim),
+            PrimFunction::new("find",        [("pattern", env.string)],                  env.int,    string_find),
+            PrimFunction::new("replace",     [("from", env.string), ("to", env.string)], env.string, string_replace),
+            PrimFunction::new("starts_with", [("prefix", env.string)],                   env.bool,   string_starts_with),
+            PrimFunction::new("ends_with",   [("suffix", env.string)],                   env.bool,   string_ends_with),
+            PrimFunction::new("upper",       [],                                         env.string, string_upper),
+            PrimFunction::new("lower",       [],                                         env.string, string_lower),
+            PrimFunction::new("chars",       [],                                         env.any,    string_chars),
+            PrimFunction::new("parse_int",   [],                                         env.int,    string_parse_int),
+            PrimFunction::new("parse_float", [],                                         env.float,  string_parse_float),
+            PrimFunction::new("repeat",      [("count", env.int)],                       env.string, string_repeat),
         ]),
     ]
 }
 
-pub fn get_list_methods<'a>(env: &Env<'a>, _: GcClass<'a>, _args: &[GcClass<'a>]) -> [PrimFunction<'a>; 6] {
+pub fn get_list_methods<'a>(env: &Env<'a>, class: GcClass<'a>, args: &[GcClass<'a>]) -> [PrimFunction<'a>; 19] {
     [
         PrimFunction::new("__str__", [], env.string, list_str),
         PrimFunction::new("__cl__",  [("args", env.any)], env.int, list_cl),
-        PrimFunction::new_rest("insert",  [("index", env.int)], ("elems", env.any), env.void, list_insert),
-        PrimFunction::new_rest("prepend", [], ("elems", env.any), env.void, list_prepend),
-        PrimFunction::new_rest("append", [], ("elems", env.any), env.void, list_append),
+        PrimFunction::new("__eq__",  [("other", env.any)], env.bool, list_eq),
+        PrimFunction::new("__add__", [("other", env.any)], class, list_add),
+        PrimFunction::new_rest("insert",  [("index", env.int)], ("elems", args[0]), env.void, list_insert),
+        PrimFunction::new_rest("prepend", [], ("elems", args[0]), env.void, list_prepend),
+        PrimFunction::new_rest("append", [], ("elems", args[0]), env.void, list_append),
         PrimFunction::new("remove",  [("index", env.int)], env.void, list_remove),
+        PrimFunction::new("len",  [], env.int, list_len),
+        PrimFunction::new("get",  [("index", env.int)], args[0], list_get),
+        PrimFunction::new("pop",  [], args[0], list_pop),
+        PrimFunction::new("slice",  [("start", env.int), ("end", env.int)], class, list_slice),
+        PrimFunction::new_rest("sort", [], ("comparator", env.any), env.void, list_sort),
+        PrimFunction::new("reverse",  [], env.void, list_reverse),
+        PrimFunction::new("contains",  [("value", env.any)], env.bool, list_contains),
+        PrimFunction::new("index_of",  [("value", env.any)], env.int, list_index_of),
+        PrimFunction::new("map",  [("function", env.any)], env.any, list_map),
+        PrimFunction::new("filter",  [("function", env.any)], class, list_filter),
+        PrimFunction::new("reduce",  [("initial", env.any), ("function", env.any)], env.any, list_reduce),
+    ]
+}
+
+pub fn get_map_methods<'a>(env: &Env<'a>, _: GcClass<'a>, args: &[GcClass<'a>]) -> [PrimFunction<'a>; 8] {
+    [
+        PrimFunction::new("__str__", [],                                      env.string, map_str),
+        PrimFunction::new("get",     [("key", args[0])],                      args[1],    map_get),
+        PrimFunction::new("set",     [("key", args[0]), ("value", args[1])], env.void,   map_set),
+        PrimFunction::new("has",     [("key", args[0])],                      env.bool,   map_has),
+        PrimFunction::new("remove",  [("key", args[0])],                      env.void,   map_remove),
+        PrimFunction::new("keys",    [],                                      env.any,    map_keys),
+        PrimFunction::new("values",  [],                                      env.any,    map_values),
+        PrimFunction::new("len",     [],                                      env.int,    map_len),
     ]
 }
 
+fn call<'a>(engine: &mut Engine<'a>, function: Value<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let args = engine.new_list(args);
+    function.call_method(engine, engine.frame().pos(), "__cl__", &[args])
+}
+
+fn root<'a>(engine: &mut Engine<'a>, values: Box<[Value<'a>]>) -> Box<[Value<'a>]> {
+    for value in values.iter().copied() {
+        engine.root(value);
+    }
+
+    values
+}
+
+fn call_arg<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let values = args[1].as_list().values();
+    match *values {
+        [value] => Ok(value),
+        _ => {
+            let args = [&args[.. 1], &values].concat();
+            error_function_call_arity(engine.frame().pos(), engine.frame().function().unwrap(), &args)
+        },
+    }
+}
+
+fn hash(value: impl Hash) -> i64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish() as i64
+}
+
+fn identity(value: Value<'_>) -> Option<GcRef<()>> {
+    match value.data {
+        Data::Class(class)       => Some(class.anonymize()),
+        Data::Function(function) => Some(function.anonymize()),
+        Data::Generic(generic)   => Some(generic.anonymize()),
+        Data::List(list)         => Some(list.anonymize()),
+        Data::Map(map)           => Some(map.anonymize()),
+        Data::Method(method)     => Some(method.anonymize()),
+        Data::Module(module)     => Some(module.anonymize()),
+        Data::Object(object)     => Some(object.anonymize()),
+        Data::String(string)     => Some(string.anonymize()),
+        _                        => None,
+    }
+}
+
 fn any_str<'a>(engine: &mut Engine<'a>, _: &[Value<'a>]) -> ResValue<'a> {
     Ok(engine.new_string("[OBJECT]"))
 }
 
+fn any_hash<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_int(hash(identity(args[0]))))
+}
+
+fn any_eq<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let left = identity(args[0]);
+    Ok(engine.new_bool(left.is_some() && left == identity(args[1])))
+}
+
 fn any_cn<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     let receiver = args[0];
-    let method = receiver.class.get_method(args[1].as_string().as_ref()).unwrap();
-    Ok(engine.new_method(receiver, method))
+    let name = args[1].as_string();
+    if let Some(method) = receiver.class.get_method(name.as_ref()) {
+        return Ok(engine.new_method(receiver, method));
+    }
+
+    error_member(engine.frame().pos(), receiver.class, name.as_ref())
 }
 
 fn any_ne<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
@@ -132,10 +300,177 @@ fn any_ge<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     Ok(engine.new_bool(!result.as_bool()))
 }
 
+fn bigint_str<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_string(&args[0].as_bigint().to_string()))
+}
+
+fn bigint_hash<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let x = args[0].as_bigint();
+    Ok(engine.new_int(match x.to_i64() {
+        Some(x) => hash(x),
+        None => hash(&*x),
+    }))
+}
+
+fn bigint_eq<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let x = args[0].as_bigint();
+    let y = args[1];
+    let eq = if y.isa(engine.env.float) {
+        x.to_f64() == y.as_float()
+    } else if y.isa(engine.env.int) || y.isa(engine.env.bigint) {
+        *x == bigint_operand(engine, y)?
+    } else {
+        false
+    };
+
+    Ok(engine.new_bool(eq))
+}
+
+fn bigint_lt<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let x = args[0].as_bigint();
+    let y = args[1];
+    if y.isa(engine.env.float) {
+        return Ok(engine.new_bool(x.to_f64() < y.as_float()));
+    }
+
+    let y = bigint_operand(engine, y)?;
+    Ok(engine.new_bool(*x < y))
+}
+
+fn bigint_pos<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_bigint((*args[0].as_bigint()).clone()))
+}
+
+fn bigint_neg<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_bigint(args[0].as_bigint().neg()))
+}
+
+fn bigint_add<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    bigint_arithmetic(engine, &args[0].as_bigint(), args[1], |x, y| Some(x.add(y)), |x, y| x + y)
+}
+
+fn bigint_sub<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    bigint_arithmetic(engine, &args[0].as_bigint(), args[1], |x, y| Some(x.sub(y)), |x, y| x - y)
+}
+
+fn bigint_mul<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    bigint_arithmetic(engine, &args[0].as_bigint(), args[1], |x, y| Some(x.mul(y)), |x, y| x * y)
+}
+
+fn bigint_div<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    bigint_arithmetic(engine, &args[0].as_bigint(), args[1], |x, y| x.div_rem(y).map(|(z, _)| z), |x, y| x / y)
+}
+
+fn bigint_rem<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    bigint_arithmetic(engine, &args[0].as_bigint(), args[1], |x, y| x.div_rem(y).map(|(_, z)| z), |x, y| x % y)
+}
+
+fn bigint_pow<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let x = args[0].as_bigint();
+    if args[1].isa(engine.env.float) {
+        return Ok(engine.new_float(x.to_f64().powf(args[1].as_float())));
+    }
+
+    let y = bigint_operand(engine, args[1])?;
+    if y.is_negative() {
+        return Ok(engine.new_float(x.to_f64().powf(y.to_f64())));
+    }
+
+    match y.to_i64().and_then(|y| u32::try_from(y).ok()) {
+        Some(y) => Ok(engine.new_bigint(x.pow(y))),
+        None => error_overflow(engine.frame().pos(), &format!("{} ** {}", *x, y)),
+    }
+}
+
+fn bigint_bnot<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_bigint(args[0].as_bigint().not()))
+}
+
+fn bigint_band<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let y = bigint_operand(engine, args[1])?;
+    Ok(engine.new_bigint(args[0].as_bigint().and(&y)))
+}
+
+fn bigint_bor<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let y = bigint_operand(engine, args[1])?;
+    Ok(engine.new_bigint(args[0].as_bigint().or(&y)))
+}
+
+fn bigint_bxor<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let y = bigint_operand(engine, args[1])?;
+    Ok(engine.new_bigint(args[0].as_bigint().xor(&y)))
+}
+
+fn bigint_bls<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let x = args[0].as_bigint();
+    let y = args[1].as_int();
+    match u32::try_from(y) {
+        Ok(y) => Ok(engine.new_bigint(x.shl(y as usize))),
+        Err(_) => error_overflow(engine.frame().pos(), &format!("{} << {y}", *x)),
+    }
+}
+
+fn bigint_brs<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let x = args[0].as_bigint();
+    let y = args[1].as_int();
+    match u32::try_from(y) {
+        Ok(y) => Ok(engine.new_bigint(x.shr(y as usize))),
+        Err(_) => error_overflow(engine.frame().pos(), &format!("{} >> {y}", *x)),
+    }
+}
+
+fn bigint_abs<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_bigint(args[0].as_bigint().abs()))
+}
+
+fn bigint_to_float<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_float(args[0].as_bigint().to_f64()))
+}
+
+fn bigint_to_int<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let x = args[0].as_bigint();
+    match x.to_i64() {
+        Some(x) => Ok(engine.new_int(x)),
+        None => error_convert(engine.frame().pos(), &x.to_string(), engine.env.int),
+    }
+}
+
+fn bigint_arithmetic<'a>(
+    engine: &mut Engine<'a>,
+    x: &BigInt,
+    y: Value<'a>,
+    bigint: fn(&BigInt, &BigInt) -> Option<BigInt>,
+    float: fn(f64, f64) -> f64,
+) -> ResValue<'a> {
+    if y.isa(engine.env.float) {
+        return Ok(engine.new_float(float(x.to_f64(), y.as_float())));
+    }
+
+    let y = bigint_operand(engine, y)?;
+    match bigint(x, &y) {
+        Some(z) => Ok(engine.new_bigint(z)),
+        None => error_division(engine.frame().pos()),
+    }
+}
+
+fn bigint_operand<'a>(engine: &mut Engine<'a>, value: Value<'a>) -> Res<BigInt> {
+    if value.isa(engine.env.bigint) {
+        Ok((*value.as_bigint()).clone())
+    } else if value.isa(engine.env.int) {
+        Ok(BigInt::from_i64(value.as_int()))
+    } else {
+        error_type(engine.frame().pos(), value, engine.env.bigint)
+    }
+}
+
 fn bool_str<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     Ok(engine.new_string(&args[0].as_bool().to_string()))
 }
 
+fn bool_hash<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_int(hash(args[0].as_bool())))
+}
+
 fn bool_eq<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     Ok(engine.new_bool(if args[1].isa(engine.env.bool) {
         args[0].as_bool() == args[1].as_bool()
@@ -149,13 +484,35 @@ fn bool_not<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
 }
 
 fn class_cl<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    let class = args[0].as_class();
-    let args = args[1].as_list();
-    if let Some(init) = class.get_static("__init__") {
-        return init.as_function().call(engine, engine.frame().pos(), &args.values());
+    args[0].as_class().instantiate(engine, engine.frame().pos(), &args[1].as_list().values())
+}
+
+fn class_cn<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let receiver = args[0];
+    let name = args[1].as_string();
+    if let Some(method) = receiver.class.get_method(name.as_ref()) {
+        return Ok(engine.new_method(receiver, method));
+    }
+
+    let class = receiver.as_class();
+    if let Some(member) = class.get_method(name.as_ref()).or_else(|| class.get_static(name.as_ref())) {
+        return Ok(member);
     }
 
-    todo!("ERROR");
+    error_member(engine.frame().pos(), class, name.as_ref())
+}
+
+fn error_str<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let class = args[0].class.name.clone();
+    let message = args[0].as_object().get_attr("message", engine.env.any).read(engine.frame().pos());
+    let string = if let Ok(message) = message {
+        let message = message.call_method(engine, engine.frame().pos(), "__str__", &[])?.as_string();
+        format!("{}: {}", class, message.as_ref())
+    } else {
+        class.to_string()
+    };
+
+    Ok(engine.new_string(&string))
 }
 
 fn function_cl<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
@@ -166,16 +523,27 @@ fn float_str<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     Ok(engine.new_string(&args[0].as_float().to_string()))
 }
 
-fn float_eq<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_bool(if args[1].isa(engine.env.float) {
-        args[0].as_float() == args[1].as_float()
+fn float_hash<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let x = args[0].as_float();
+    Ok(engine.new_int(if x.fract() == 0.0 && x >= i64::MIN as f64 && x < i64::MAX as f64 {
+        hash(x as i64)
     } else {
-        false
+        hash(x.to_bits())
     }))
 }
 
+fn float_eq<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    if args[1].isa(engine.env.int) {
+        return Ok(engine.new_bool(int_float_eq(args[1].as_int(), args[0].as_float())));
+    }
+
+    let y = number(engine, args[1]);
+    Ok(engine.new_bool(y == Some(args[0].as_float())))
+}
+
 fn float_lt<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_bool(args[0].as_float() < args[1].as_float()))
+    let y = float_operand(engine, args[1])?;
+    Ok(engine.new_bool(args[0].as_float() < y))
 }
 
 fn float_pos<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
@@ -187,39 +555,99 @@ fn float_neg<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
 }
 
 fn float_add<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_float(args[0].as_float() + args[1].as_float()))
+    let y = float_operand(engine, args[1])?;
+    Ok(engine.new_float(args[0].as_float() + y))
 }
 
 fn float_sub<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_float(args[0].as_float() - args[1].as_float()))
+    let y = float_operand(engine, args[1])?;
+    Ok(engine.new_float(args[0].as_float() - y))
 }
 
 fn float_mul<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_float(args[0].as_float() * args[1].as_float()))
+    let y = float_operand(engine, args[1])?;
+    Ok(engine.new_float(args[0].as_float() * y))
 }
 
 fn float_div<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_float(args[0].as_float() / args[1].as_float()))
+    let y = float_operand(engine, args[1])?;
+    Ok(engine.new_float(args[0].as_float() / y))
 }
 
 fn float_rem<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_float(args[0].as_float() % args[1].as_float()))
+    let y = float_operand(engine, args[1])?;
+    Ok(engine.new_float(args[0].as_float() % y))
+}
+
+fn float_pow<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let y = float_operand(engine, args[1])?;
+    Ok(engine.new_float(args[0].as_float().powf(y)))
+}
+
+fn float_abs<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_float(args[0].as_float().abs()))
+}
+
+fn float_ceil<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_float(args[0].as_float().ceil()))
+}
+
+fn float_floor<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_float(args[0].as_float().floor()))
+}
+
+fn float_round<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_float(args[0].as_float().round()))
+}
+
+fn float_to_float<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_float(args[0].as_float()))
+}
+
+fn float_to_int<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let x = args[0].as_float().trunc();
+    if x.is_nan() || x < i64::MIN as f64 || x >= i64::MAX as f64 {
+        return error_convert(engine.frame().pos(), &args[0].as_float().to_string(), engine.env.int);
+    }
+
+    Ok(engine.new_int(x as i64))
 }
 
 fn int_str<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     Ok(engine.new_string(&args[0].as_int().to_string()))
 }
 
+fn int_hash<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_int(hash(args[0].as_int())))
+}
+
 fn int_eq<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     Ok(engine.new_bool(if args[1].isa(engine.env.int) {
         args[0].as_int() == args[1].as_int()
+    } else if args[1].isa(engine.env.float) {
+        int_float_eq(args[0].as_int(), args[1].as_float())
+    } else if args[1].isa(engine.env.bigint) {
+        BigInt::from_i64(args[0].as_int()) == *args[1].as_bigint()
     } else {
         false
     }))
 }
 
+fn int_float_eq(x: i64, y: f64) -> bool {
+    y.fract() == 0.0 && y >= i64::MIN as f64 && y < i64::MAX as f64 && x == y as i64
+}
+
 fn int_lt<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_bool(args[0].as_int() < args[1].as_int()))
+    if args[1].isa(engine.env.float) {
+        return Ok(engine.new_bool((args[0].as_int() as f64) < args[1].as_float()));
+    }
+
+    if args[1].isa(engine.env.bigint) {
+        return Ok(engine.new_bool(BigInt::from_i64(args[0].as_int()) < *args[1].as_bigint()));
+    }
+
+    let y = int_operand(engine, args[1])?;
+    Ok(engine.new_bool(args[0].as_int() < y))
 }
 
 fn int_pos<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
@@ -227,27 +655,83 @@ fn int_pos<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
 }
 
 fn int_neg<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_int(-args[0].as_int()))
+    let x = args[0].as_int();
+    match x.checked_neg() {
+        Some(z) => Ok(engine.new_int(z)),
+        None => error_overflow(engine.frame().pos(), &format!("-({x})")),
+    }
 }
 
 fn int_add<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_int(args[0].as_int() + args[1].as_int()))
+    int_arithmetic(engine, args, "+", i64::checked_add, bigint_add)
 }
 
 fn int_sub<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_int(args[0].as_int() - args[1].as_int()))
+    int_arithmetic(engine, args, "-", i64::checked_sub, bigint_sub)
 }
 
 fn int_mul<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_int(args[0].as_int() * args[1].as_int()))
+    int_arithmetic(engine, args, "*", i64::checked_mul, bigint_mul)
 }
 
 fn int_div<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_int(args[0].as_int() / args[1].as_int()))
+    if args[1].isa(engine.env.int) && args[1].as_int() == 0 {
+        return error_division(engine.frame().pos());
+    }
+
+    int_arithmetic(engine, args, "/", i64::checked_div, bigint_div)
 }
 
 fn int_rem<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_int(args[0].as_int() % args[1].as_int()))
+    if args[1].isa(engine.env.int) && args[1].as_int() == 0 {
+        return error_division(engine.frame().pos());
+    }
+
+    int_arithmetic(engine, args, "%", i64::checked_rem, bigint_rem)
+}
+
+fn int_pow<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let x = args[0].as_int();
+    if args[1].isa(engine.env.float) {
+        return Ok(engine.new_float((x as f64).powf(args[1].as_float())));
+    }
+
+    let y = int_operand(engine, args[1])?;
+    if y < 0 {
+        return Ok(engine.new_float((x as f64).powf(y as f64)));
+    }
+
+    let z = match x {
+        0 => Some(if y == 0 { 1 } else { 0 }),
+        1 => Some(1),
+        -1 => Some(if y % 2 == 0 { 1 } else { -1 }),
+        _ => u32::try_from(y).ok().and_then(|y| x.checked_pow(y)),
+    };
+
+    match z {
+        Some(z) => Ok(engine.new_int(z)),
+        None => error_overflow(engine.frame().pos(), &format!("{x} ** {y}")),
+    }
+}
+
+fn int_abs<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let x = args[0].as_int();
+    match x.checked_abs() {
+        Some(z) => Ok(engine.new_int(z)),
+        None => error_overflow(engine.frame().pos(), &format!("{x}.abs()")),
+    }
+}
+
+fn int_to_bigint<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_bigint(BigInt::from_i64(args[0].as_int())))
+}
+
+fn int_to_float<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_float(args[0].as_int() as f64))
+}
+
+fn int_to_int<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_int(args[0].as_int()))
 }
 
 fn int_bnot<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
@@ -267,23 +751,82 @@ fn int_bxor<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
 }
 
 fn int_bls<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_int(args[0].as_int() << args[1].as_int()))
+    int_shift(engine, args, "<<", i64::checked_shl)
 }
 
 fn int_brs<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_int(args[0].as_int() >> args[1].as_int()))
+    int_shift(engine, args, ">>", i64::checked_shr)
 }
 
 fn int_bcls<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    let x = args[0].as_int();
-    let y = args[1].as_int();
-    Ok(engine.new_int((x << y) | (x >> (-y & size_of::<i64>() as i64))))
+    let y = args[1].as_int().rem_euclid(i64::BITS as i64) as u32;
+    Ok(engine.new_int(args[0].as_int().rotate_left(y)))
 }
 
 fn int_bcrs<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let y = args[1].as_int().rem_euclid(i64::BITS as i64) as u32;
+    Ok(engine.new_int(args[0].as_int().rotate_right(y)))
+}
+
+fn int_arithmetic<'a>(
+    engine: &mut Engine<'a>,
+    args: &[Value<'a>],
+    operator: &str,
+    int: fn(i64, i64) -> Option<i64>,
+    other: for<'b> fn(&mut Engine<'b>, &[Value<'b>]) -> ResValue<'b>,
+) -> ResValue<'a> {
+    let x = args[0].as_int();
+    if args[1].isa(engine.env.float) || args[1].isa(engine.env.bigint) {
+        let x = engine.new_bigint(BigInt::from_i64(x));
+        return other(engine, &[x, args[1]]);
+    }
+
+    let y = int_operand(engine, args[1])?;
+    match int(x, y) {
+        Some(z) => Ok(engine.new_int(z)),
+        None => error_overflow(engine.frame().pos(), &format!("{x} {operator} {y}")),
+    }
+}
+
+fn int_shift<'a>(engine: &mut Engine<'a>, args: &[Value<'a>], operator: &str, shift: fn(i64, u32) -> Option<i64>) -> ResValue<'a> {
     let x = args[0].as_int();
     let y = args[1].as_int();
-    Ok(engine.new_int((x >> y) | (x << (-y & size_of::<i64>() as i64))))
+    match u32::try_from(y).ok().and_then(|y| shift(x, y)) {
+        Some(z) => Ok(engine.new_int(z)),
+        None => error_overflow(engine.frame().pos(), &format!("{x} {operator} {y}")),
+    }
+}
+
+fn int_operand<'a>(engine: &mut Engine<'a>, value: Value<'a>) -> Res<i64> {
+    value.isa_type(engine.frame().pos(), engine.env.int)?;
+    Ok(value.as_int())
+}
+
+fn float_operand<'a>(engine: &mut Engine<'a>, value: Value<'a>) -> Res<f64> {
+    match number(engine, value) {
+        Some(number) => Ok(number),
+        None => error_type(engine.frame().pos(), value, engine.env.float),
+    }
+}
+
+fn number<'a>(engine: &Engine<'a>, value: Value<'a>) -> Option<f64> {
+    if value.isa(engine.env.int) {
+        Some(value.as_int() as f64)
+    } else if value.isa(engine.env.bigint) {
+        Some(value.as_bigint().to_f64())
+    } else if value.isa(engine.env.float) {
+        Some(value.as_float())
+    } else {
+        None
+    }
+}
+
+fn int_range<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_range(Range::new(args[0].as_int(), args[1].as_int(), 1, false)))
+}
+
+fn int_range_eq<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_range(Range::new(args[0].as_int(), args[1].as_int(), 1, true)))
 }
 
 fn method_cl<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
@@ -295,25 +838,116 @@ fn method_cl<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     method.function.as_function().call(engine, engine.frame().pos(), &args)
 }
 
-fn list_str<'a>(engine: &mut Engine<'a>, _: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_string("[LIST]"))
+fn list_index<'a>(engine: &Engine<'a>, index: i64, length: usize) -> Res<usize> {
+    match usize::try_from(index) {
+        Ok(index) if index < length => Ok(index),
+        _ => error_index(engine.frame().pos(), index, length),
+    }
+}
+
+fn list_equal<'a>(engine: &mut Engine<'a>, left: Value<'a>, right: Value<'a>) -> Res<bool> {
+    let equal = left.call_method(engine, engine.frame().pos(), "__eq__", &[right])?;
+    equal.isa_type(engine.frame().pos(), engine.env.bool)?;
+    Ok(equal.as_bool())
+}
+
+fn list_less<'a>(engine: &mut Engine<'a>, comparator: Option<Value<'a>>, left: Value<'a>, right: Value<'a>) -> Res<bool> {
+    let less = match comparator {
+        Some(comparator) => call(engine, comparator, &[left, right])?,
+        None => left.call_method(engine, engine.frame().pos(), "__lt__", &[right])?,
+    };
+
+    less.isa_type(engine.frame().pos(), engine.env.bool)?;
+    Ok(less.as_bool())
+}
+
+fn list_merge_sort<'a>(engine: &mut Engine<'a>, comparator: Option<Value<'a>>, values: &[Value<'a>]) -> Res<Vec<Value<'a>>> {
+    if values.len() <= 1 {
+        return Ok(values.to_vec());
+    }
+
+    let (left, right) = values.split_at(values.len() / 2);
+    let left = list_merge_sort(engine, comparator, left)?;
+    let right = list_merge_sort(engine, comparator, right)?;
+    let mut sorted = Vec::with_capacity(values.len());
+    let mut i = 0;
+    let mut j = 0;
+    while i < left.len() && j < right.len() {
+        if list_less(engine, comparator, right[j], left[i])? {
+            sorted.push(right[j]);
+            j += 1;
+        } else {
+            sorted.push(left[i]);
+            i += 1;
+        }
+    }
+
+    sorted.extend_from_slice(&left[i ..]);
+    sorted.extend_from_slice(&right[j ..]);
+    Ok(sorted)
+}
+
+fn list_str<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let elements = root(engine, args[0].as_list().values()).iter()
+        .map(|value| Ok(value.call_method(engine, engine.frame().pos(), "__str__", &[])?.as_string().as_ref().to_string()))
+        .collect::<Res<Box<[_]>>>()?;
+
+    Ok(engine.new_string(&format!("[{}]", elements.join(", "))))
 }
 
 fn list_cl<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    let index = args[1].as_list().values()[0].as_int() as usize;
+    let index = call_arg(engine, args)?;
+    index.isa_type(engine.frame().pos(), engine.env.int)?;
+    let index = list_index(engine, index.as_int(), args[0].as_list().len())?;
     let r#ref = args[0].as_list().get_ref(index);
     Ok(engine.new_ref(r#ref))
 }
 
+fn list_eq<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    if !matches!(args[1].data, Data::List(_)) {
+        return Ok(engine.new_bool(false));
+    }
+
+    let left = root(engine, args[0].as_list().values());
+    let right = root(engine, args[1].as_list().values());
+    if left.len() != right.len() {
+        return Ok(engine.new_bool(false));
+    }
+
+    for (left, right) in left.iter().copied().zip(right.iter().copied()) {
+        if !list_equal(engine, left, right)? {
+            return Ok(engine.new_bool(false));
+        }
+    }
+
+    Ok(engine.new_bool(true))
+}
+
+fn list_add<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    if !matches!(args[1].data, Data::List(_)) {
+        return error_type(engine.frame().pos(), args[1], engine.env.list_any);
+    }
+
+    let class = args[0].class;
+    let mut values = args[0].as_list().values().into_vec();
+    for value in args[1].as_list().values().iter().copied() {
+        value.isa_type(engine.frame().pos(), class.generics[0])?;
+        values.push(value);
+    }
+
+    Ok(engine.new_list_class(class, &values))
+}
+
 fn list_insert<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    let index = args[1].as_int() as usize;
-    args[0].as_list().insert(engine.env.any, index, args[2]);
+    let length = args[0].as_list().len();
+    let index = list_index(engine, args[1].as_int(), length + 1)?;
+    args[0].as_list().insert(args[0].class.generics[0], index, args[2]);
     Ok(engine.new_void())
 }
 
 fn list_append<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     for index in 1 .. args.len() {
-        args[0].as_list().append(engine.env.any, args[index]);
+        args[0].as_list().append(args[0].class.generics[0], args[index]);
     }
 
     Ok(engine.new_void())
@@ -321,22 +955,207 @@ fn list_append<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a>
 
 fn list_prepend<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     for index in 1 .. args.len() {
-        args[0].as_list().insert(engine.env.any, index - 1, args[index]);
+        args[0].as_list().insert(args[0].class.generics[0], index - 1, args[index]);
     }
 
     Ok(engine.new_void())
 }
 
 fn list_remove<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    let index = args[1].as_int() as usize;
+    let index = list_index(engine, args[1].as_int(), args[0].as_list().len())?;
     args[0].as_list().remove(index);
     Ok(engine.new_void())
 }
 
+fn list_len<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_int(args[0].as_list().len() as i64))
+}
+
+fn list_get<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let index = list_index(engine, args[1].as_int(), args[0].as_list().len())?;
+    Ok(args[0].as_list().get(index))
+}
+
+fn list_pop<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let length = args[0].as_list().len();
+    let index = list_index(engine, length as i64 - 1, length)?;
+    Ok(args[0].as_list().remove(index))
+}
+
+fn list_slice<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let values = args[0].as_list().values();
+    let start = args[1].as_int();
+    let end = args[2].as_int();
+    if start < 0 || start > end || end as usize > values.len() {
+        return error_slice(engine.frame().pos(), start, end, values.len());
+    }
+
+    Ok(engine.new_list_class(args[0].class, &values[start as usize .. end as usize]))
+}
+
+fn list_sort<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let comparator = args.get(1).copied();
+    let values = root(engine, args[0].as_list().values());
+    let sorted = list_merge_sort(engine, comparator, &values)?;
+    *args[0].as_list() = List::new(args[0].class.generics[0], &sorted);
+    Ok(engine.new_void())
+}
+
+fn list_reverse<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    args[0].as_list().reverse();
+    Ok(engine.new_void())
+}
+
+fn list_contains<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let index = list_index_of(engine, args)?.as_int();
+    Ok(engine.new_bool(index != -1))
+}
+
+fn list_index_of<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    for (index, value) in root(engine, args[0].as_list().values()).iter().copied().enumerate() {
+        if list_equal(engine, value, args[1])? {
+            return Ok(engine.new_int(index as i64));
+        }
+    }
+
+    Ok(engine.new_int(-1))
+}
+
+fn list_map<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let values = root(engine, args[0].as_list().values()).iter()
+        .map(|value| call(engine, args[1], &[*value]))
+        .collect::<Res<Box<[_]>>>()?;
+
+    Ok(engine.new_list(&values))
+}
+
+fn list_filter<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let mut values = Vec::new();
+    for value in root(engine, args[0].as_list().values()).iter().copied() {
+        let keep = call(engine, args[1], &[value])?;
+        keep.isa_type(engine.frame().pos(), engine.env.bool)?;
+        if keep.as_bool() {
+            values.push(value);
+        }
+    }
+
+    Ok(engine.new_list_class(args[0].class, &values))
+}
+
+fn list_reduce<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let mut accumulator = args[1];
+    for value in root(engine, args[0].as_list().values()).iter().copied() {
+        accumulator = call(engine, args[2], &[accumulator, value])?;
+    }
+
+    Ok(accumulator)
+}
+
+fn map_find<'a>(engine: &mut Engine<'a>, map: Value<'a>, key: Value<'a>) -> Res<(i64, Option<usize>)> {
+    let pos = engine.frame().pos();
+    let hash = key.call_method(engine, pos, "__hash__", &[])?;
+    hash.isa_type(pos, engine.env.int)?;
+    let hash = hash.as_int();
+    let bucket = map.as_map().bucket(hash).into_vec();
+    for index in bucket {
+        if index >= map.as_map().len() {
+            continue;
+        }
+
+        let other = map.as_map().key(index);
+        let equal = key.call_method(engine, pos, "__eq__", &[other])?;
+        equal.isa_type(pos, engine.env.bool)?;
+        if equal.as_bool() && index < map.as_map().len() {
+            return Ok((hash, Some(index)));
+        }
+    }
+
+    Ok((hash, None))
+}
+
+fn map_str<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let map = args[0].as_map();
+    let mut string = "{".to_string();
+    let keys = root(engine, map.keys());
+    let values = root(engine, map.values());
+    string.push_str(&keys.iter().copied().zip(values.iter().copied())
+        .map(|(key, value)| Ok(format!("{}: {}",
+            key.call_method(engine, engine.frame().pos(), "__str__", &[])?.as_string().as_ref(),
+            value.call_method(engine, engine.frame().pos(), "__str__", &[])?.as_string().as_ref(),
+        )))
+        .collect::<Res<Box<[_]>>>()?
+        .join(", ")
+    );
+
+    string.push('}');
+    Ok(engine.new_string(&string))
+}
+
+fn map_get<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    match map_find(engine, args[0], args[1])? {
+        (_, Some(index)) => Ok(args[0].as_map().get(index)),
+        (_, None) => {
+            let key = args[1].call_method(engine, engine.frame().pos(), "__str__", &[])?.as_string();
+            error_key(engine.frame().pos(), key.as_ref())
+        },
+    }
+}
+
+fn map_set<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let class = args[0].class.generics[1];
+    match map_find(engine, args[0], args[1])? {
+        (_, Some(index)) => args[0].as_map().replace(class, index, args[2]),
+        (hash, None) => args[0].as_map().insert(class, hash, args[1], args[2]),
+    }
+
+    Ok(engine.new_void())
+}
+
+fn map_has<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let (_, index) = map_find(engine, args[0], args[1])?;
+    Ok(engine.new_bool(index.is_some()))
+}
+
+fn map_remove<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    if let (_, Some(index)) = map_find(engine, args[0], args[1])? {
+        args[0].as_map().remove(index);
+    }
+
+    Ok(engine.new_void())
+}
+
+fn map_keys<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    engine.new_list_of(engine.frame().pos(), args[0].class.generics[0], &args[0].as_map().keys())
+}
+
+fn map_values<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    engine.new_list_of(engine.frame().pos(), args[0].class.generics[1], &args[0].as_map().values())
+}
+
+fn map_len<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_int(args[0].as_map().len() as i64))
+}
+
+fn module_cn<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let mut module = args[0].as_module();
+    let name = args[1].as_string();
+    match module.get_export(name.as_ref()) {
+        Some(r#ref) => r#ref.read(engine.frame().pos()),
+        None => error_export(engine.frame().pos(), &module.name, name.as_ref()),
+    }
+}
+
 fn object_str<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let attributes = args[0].as_object().attributes.iter()
+        .filter_map(|(name, attribute)| attribute.content().map(|attribute| (name.clone(), attribute)))
+        .collect::<Box<[_]>>();
+
+    for (_, attribute) in attributes.iter() {
+        engine.root(*attribute);
+    }
+
     let mut string = "{".to_string();
-    string.push_str(&args[0].as_object().attributes.iter()
-        .filter_map(|(name, attribute)| attribute.content().map(|attribute| (name, attribute)))
+    string.push_str(&attributes.iter()
         .map(|(name, attribute)| Ok(format!("{}: {}", &name, &attribute.call_method(engine, engine.frame().pos(), "__str__", &[])?.as_string().as_ref())))
         .collect::<Res<Box<[_]>>>()?
         .join(", ")
@@ -354,17 +1173,74 @@ fn object_cn<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     }
 
     let mut object = receiver.as_object();
+    if !object.attributes.contains_key(name.as_ref()) && receiver.class.has_attributes() {
+        return error_member(engine.frame().pos(), receiver.class, name.as_ref());
+    }
+
     let r#ref = object.get_attr(name.as_ref(), engine.env.any);
     Ok(engine.new_ref(r#ref))
 }
 
+fn range_str<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let range = args[0].as_range();
+    let op = if range.inclusive { "..=" } else { ".." };
+    let mut string = format!("{} {} {}", range.start, op, range.end);
+    if range.step != 1 {
+        string.push_str(&format!(" step {}", range.step));
+    }
+
+    Ok(engine.new_string(&string))
+}
+
+fn range_contains<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_bool(args[0].as_range().contains(args[1].as_int())))
+}
+
+fn range_len<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let range = args[0].as_range();
+    match i64::try_from(range.len()) {
+        Ok(length) => Ok(engine.new_int(length)),
+        Err(_) => {
+            let op = if range.inclusive { "..=" } else { ".." };
+            error_overflow(engine.frame().pos(), &format!("({} {} {}).len()", range.start, op, range.end))
+        },
+    }
+}
+
+fn range_rev<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_range(args[0].as_range().rev()))
+}
+
+fn range_step<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let range = args[0].as_range();
+    let step = args[1].as_int();
+    if step <= 0 {
+        return error_step(engine.frame().pos(), step);
+    }
+
+    let step = if range.step < 0 { -step } else { step };
+    Ok(engine.new_range(Range::new(range.start, range.end, step, range.inclusive)))
+}
+
+fn range_to_list<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let values = args[0].as_range().iter()
+        .map(|value| engine.new_int(value))
+        .collect::<Box<_>>();
+
+    engine.new_list_of(engine.frame().pos(), engine.env.int, &values)
+}
+
 fn string_str<'a>(_: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     Ok(args[0])
 }
 
+fn string_hash<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_int(hash(args[0].as_string().as_ref())))
+}
+
 fn string_eq<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     Ok(engine.new_bool(if args[1].isa(engine.env.string) {
-        args[0].as_string() == args[1].as_string()
+        args[0].as_string().as_ref() == args[1].as_string().as_ref()
     } else {
         false
     }))
@@ -375,3 +1251,142 @@ fn string_add<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
     let right = args[1].call_method(engine, engine.frame().pos(), "__str__", &[])?.as_string();
     Ok(engine.new_string(&format!("{}{}", left.as_ref(), right.as_ref())))
 }
+
+fn string_lt<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_bool(args[0].as_string().as_ref() < args[1].as_string().as_ref()))
+}
+
+fn string_cl<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let string = args[0].as_string();
+    let index = call_arg(engine, args)?;
+    index.isa_type(engine.frame().pos(), engine.env.int)?;
+    let length = string.as_ref().chars().count();
+    match usize::try_from(index.as_int()).ok().and_then(|index| string.as_ref().chars().nth(index)) {
+        Some(character) => Ok(engine.new_string(&character.to_string())),
+        None => error_index(engine.frame().pos(), index.as_int(), length),
+    }
+}
+
+fn string_len<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_int(args[0].as_string().as_ref().chars().count() as i64))
+}
+
+fn string_slice<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let string = args[0].as_string();
+    let length = string.as_ref().chars().count();
+    let start = args[1].as_int();
+    let end = args[2].as_int();
+    if start < 0 || start > end || end as usize > length {
+        return error_slice(engine.frame().pos(), start, end, length);
+    }
+
+    let slice = string.as_ref().chars()
+        .skip(start as usize)
+        .take((end - start) as usize)
+        .collect::<String>();
+
+    Ok(engine.new_string(&slice))
+}
+
+fn string_split<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let string = args[0].as_string();
+    let separator = args[1].as_string();
+    if separator.as_ref().is_empty() {
+        return string_chars(engine, args);
+    }
+
+    let parts = string.as_ref().split(separator.as_ref())
+        .map(|part| engine.new_string(part))
+        .collect::<Box<_>>();
+
+    engine.new_list_of(engine.frame().pos(), engine.env.string, &parts)
+}
+
+fn string_join<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    if !matches!(args[1].data, Data::List(_)) {
+        return error_type(engine.frame().pos(), args[1], engine.env.list_any);
+    }
+
+    let separator = args[0].as_string();
+    let parts = root(engine, args[1].as_list().values()).iter()
+        .map(|value| Ok(value.call_method(engine, engine.frame().pos(), "__str__", &[])?.as_string().as_ref().to_string()))
+        .collect::<Res<Box<[_]>>>()?;
+
+    Ok(engine.new_string(&parts.join(separator.as_ref())))
+}
+
+fn string_trim<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_string(args[0].as_string().as_ref().trim()))
+}
+
+fn string_find<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let string = args[0].as_string();
+    let index = string.as_ref().find(args[1].as_string().as_ref())
+        .map_or(-1, |index| string.as_ref()[.. index].chars().count() as i64);
+
+    Ok(engine.new_int(index))
+}
+
+fn string_replace<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let string = args[0].as_string().as_ref().replace(args[1].as_string().as_ref(), args[2].as_string().as_ref());
+    Ok(engine.new_string(&string))
+}
+
+fn string_starts_with<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_bool(args[0].as_string().as_ref().starts_with(args[1].as_string().as_ref())))
+}
+
+fn string_ends_with<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_bool(args[0].as_string().as_ref().ends_with(args[1].as_string().as_ref())))
+}
+
+fn string_upper<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_string(&args[0].as_string().as_ref().to_uppercase()))
+}
+
+fn string_lower<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    Ok(engine.new_string(&args[0].as_string().as_ref().to_lowercase()))
+}
+
+fn string_chars<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let chars = args[0].as_string().as_ref().chars()
+        .map(|character| engine.new_string(&character.to_string()))
+        .collect::<Box<_>>();
+
+    engine.new_list_of(engine.frame().pos(), engine.env.string, &chars)
+}
+
+fn string_parse_int<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let string = args[0].as_string();
+    match string.as_ref().parse::<i64>() {
+        Ok(int) => Ok(engine.new_int(int)),
+        Err(_) => error_parse(engine.frame().pos(), string.as_ref(), engine.env.int),
+    }
+}
+
+fn string_parse_float<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let string = args[0].as_string();
+    match string.as_ref().parse::<f64>() {
+        Ok(float) => Ok(engine.new_float(float)),
+        Err(_) => error_parse(engine.frame().pos(), string.as_ref(), engine.env.float),
+    }
+}
+
+fn string_repeat<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let string = args[0].as_string();
+    let count = args[1].as_int().max(0) as usize;
+    let mut repeated = String::new();
+    let Some(length) = string.as_ref().len().checked_mul(count) else {
+        return error_repeat(engine.frame().pos(), count);
+    };
+
+    if repeated.try_reserve_exact(length).is_err() {
+        return error_repeat(engine.frame().pos(), count);
+    }
+
+    for _ in 0 .. count {
+        repeated.push_str(string.as_ref());
+    }
+
+    Ok(engine.new_string(&repeated))
+}
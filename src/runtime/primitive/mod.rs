@@ -43,6 +43,11 @@ pub fn populate(engine: &mut Engine) {
         }
     }
 
+    let prim_statics = statics::get_statics(engine);
+    for mut prim_static in prim_statics {
+        prim_static.class.add_static(prim_static.name, prim_static.value);
+    }
+
     let prim_funs = functions::get_functions(&engine.env);
     for prim_fun in prim_funs {
         let function = prim_fun.to_function(engine);
@@ -1,16 +1,77 @@
 use crate::runtime::Value;
-use crate::runtime::data::GcClass;
+use crate::runtime::data::{BigInt, GcClass};
 use crate::runtime::engine::Engine;
 use crate::runtime::env::Env;
+use crate::runtime::eval::errors::{error_parse, error_type};
 use crate::runtime::flow::ResValue;
 use crate::runtime::primitive::functions::PrimFunction;
 
-pub fn get_list_statics<'a>(env: &Env<'a>, class: GcClass<'a>) -> [PrimFunction<'a>; 1] {
+pub struct PrimStatic<'a> {
+    pub class: GcClass<'a>,
+    pub name: &'static str,
+    pub value: Value<'a>,
+}
+
+impl<'a> PrimStatic<'a> {
+    fn new(class: GcClass<'a>, name: &'static str, value: Value<'a>) -> Self {
+        Self { class, name, value }
+    }
+}
+
+pub fn get_statics<'a>(engine: &mut Engine<'a>) -> [PrimStatic<'a>; 8] {
+    let bigint = engine.env.bigint;
+    let float = engine.env.float;
+    let int = engine.env.int;
+    let bigint_init = PrimFunction::new("__init__", [("value", engine.env.any)], bigint, bigint_init).to_function(engine);
     [
-        PrimFunction::new_rest("__init__", [], ("values", env.any), class, list_init),
+        PrimStatic::new(bigint, "__init__", engine.new_function(bigint_init)),
+        PrimStatic::new(float, "EPSILON",  engine.new_float(f64::EPSILON)),
+        PrimStatic::new(float, "INFINITY", engine.new_float(f64::INFINITY)),
+        PrimStatic::new(float, "MAX",      engine.new_float(f64::MAX)),
+        PrimStatic::new(float, "MIN",      engine.new_float(f64::MIN)),
+        PrimStatic::new(float, "NAN",      engine.new_float(f64::NAN)),
+        PrimStatic::new(int,   "MAX",      engine.new_int(i64::MAX)),
+        PrimStatic::new(int,   "MIN",      engine.new_int(i64::MIN)),
     ]
 }
 
+pub fn get_list_statics<'a>(_: &Env<'a>, class: GcClass<'a>) -> [PrimFunction<'a>; 1] {
+    [
+        PrimFunction::new_rest("__init__", [], ("values", class.generics[0]), class, list_init),
+    ]
+}
+
+pub fn get_map_statics<'a>(_: &Env<'a>, class: GcClass<'a>) -> [PrimFunction<'a>; 1] {
+    [
+        PrimFunction::new("__init__", [], class, map_init),
+    ]
+}
+
+fn bigint_init<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
+    let value = args[0];
+    let bigint = if value.isa(engine.env.bigint) {
+        (*value.as_bigint()).clone()
+    } else if value.isa(engine.env.int) {
+        BigInt::from_i64(value.as_int())
+    } else if value.isa(engine.env.string) {
+        let string = value.as_string();
+        match BigInt::parse(string.as_ref().trim(), 10) {
+            Some(bigint) => bigint,
+            None => return error_parse(engine.frame().pos(), string.as_ref(), engine.env.bigint),
+        }
+    } else {
+        return error_type(engine.frame().pos(), value, engine.env.bigint);
+    };
+
+    Ok(engine.new_bigint(bigint))
+}
+
 fn list_init<'a>(engine: &mut Engine<'a>, args: &[Value<'a>]) -> ResValue<'a> {
-    Ok(engine.new_list(args))
+    let class = engine.frame().function().unwrap().ret;
+    Ok(engine.new_list_class(class, args))
+}
+
+fn map_init<'a>(engine: &mut Engine<'a>, _: &[Value<'a>]) -> ResValue<'a> {
+    let class = engine.frame().function().unwrap().ret;
+    Ok(engine.new_map(class))
 }
@@ -1,15 +1,18 @@
+use crate::memory;
 use crate::runtime::gc::{GcRef, GcTrace};
 use crate::runtime::{Value, Variable};
 use crate::runtime::data::{Ref, GcClass};
 
 use std::collections::HashMap;
-use std::collections::hash_map::Entry;
+use std::mem::size_of;
 
 pub type GcScope<'a> = GcRef<Scope<'a>>;
 
 pub struct Scope<'a> {
     pub parent: Option<GcScope<'a>>,
     variables: HashMap<Box<str>, Variable<'a>>,
+    names: Option<memory::Ref<[Box<str>]>>,
+    slots: Vec<Option<Variable<'a>>>,
 }
 
 impl<'a> Scope<'a> {
@@ -17,8 +20,16 @@ impl<'a> Scope<'a> {
         Self {
             parent,
             variables: HashMap::new(),
+            names: None,
+            slots: Vec::new(),
         }
     }
+
+    pub fn new_slots(parent: Option<GcScope<'a>>, names: &[Box<str>]) -> Self {
+        let mut scope = Self::new(parent);
+        scope.init_slots(names);
+        scope
+    }
 }
 
 impl<'a> Scope<'a> {
@@ -31,13 +42,44 @@ impl<'a> Scope<'a> {
     }
 
     pub fn get_ref(&mut self, name: &str) -> Option<Ref<'a>> {
-        if let Entry::Occupied(mut entry) = self.variables.entry(name.into()) {
-            Some(Ref::new(entry.get_mut()))
-        } else if let Some(mut parent) = self.parent {
-            parent.get_ref(name)
-        } else {
-            None
+        if let Some(variable) = self.variables.get_mut(name) {
+            return Some(variable.get_ref());
+        }
+
+        if let Some(names) = self.names {
+            let slot = names.iter()
+                .zip(self.slots.iter_mut())
+                .find_map(|(slot, variable)| if slot.as_ref() == name { variable.as_mut() } else { None });
+
+            if let Some(variable) = slot {
+                return Some(variable.get_ref());
+            }
         }
+
+        self.parent.and_then(|mut parent| parent.get_ref(name))
+    }
+}
+
+impl<'a> Scope<'a> {
+    pub fn init_slots(&mut self, names: &[Box<str>]) {
+        self.names = Some(memory::Ref::new(names));
+        self.slots = names.iter().map(|_| None).collect();
+    }
+
+    pub fn declare_slot(&mut self, index: usize, class: GcClass<'a>) {
+        self.slots[index] = Some(Variable::undefined(class));
+    }
+
+    pub fn set_slot(&mut self, index: usize, class: GcClass<'a>, value: Value<'a>) {
+        self.slots[index] = Some(Variable::value(class, value));
+    }
+
+    pub fn get_slot(&mut self, depth: usize, index: usize) -> Option<Ref<'a>> {
+        if depth > 0 {
+            return self.parent.and_then(|mut parent| parent.get_slot(depth - 1, index));
+        }
+
+        self.slots[index].as_mut().map(Variable::get_ref)
     }
 }
 
@@ -50,5 +92,13 @@ impl GcTrace for Scope<'_> {
         for variable in self.variables.values_mut() {
             variable.trace();
         }
+
+        for variable in self.slots.iter_mut().flatten() {
+            variable.trace();
+        }
+    }
+
+    fn size(&self) -> usize {
+        self.variables.capacity() * size_of::<(Box<str>, Variable)>() + self.slots.capacity() * size_of::<Option<Variable>>()
     }
 }
@@ -1,5 +1,5 @@
 use crate::ast::Pos;
-use crate::runtime::data::{Data, Ref, GcClass, GcFunction, GcGeneric, GcList, GcMethod, GcObject, GcString};
+use crate::runtime::data::{Data, Ref, GcBigInt, GcClass, GcFunction, GcGeneric, GcList, GcMap, GcMethod, GcModule, GcObject, GcRange, GcString};
 use crate::runtime::eval::errors::error_type;
 use crate::runtime::flow::Res;
 use crate::runtime::gc::{GcRef, GcTrace};
@@ -18,6 +18,13 @@ impl<'a> Value<'a> {
     pub fn dummy() -> Self {
         Self { class: GcRef::null(), data: Data::Void(()) }
     }
+
+    pub fn store(self) {
+        self.class.store();
+        if let Some(data) = self.data.anonymize() {
+            data.store();
+        }
+    }
 }
 
 impl<'a> Value<'a> {
@@ -47,6 +54,13 @@ impl<'a> Value<'a> {
         }
     }
 
+    pub fn as_bigint(self) -> GcBigInt {
+        match self.data {
+            Data::BigInt(bigint) => bigint,
+            _ => panic!("expected bigint"),
+        }
+    }
+
     pub fn as_class(self) -> GcClass<'a> {
         match self.data {
             Data::Class(class) => class,
@@ -89,6 +103,21 @@ impl<'a> Value<'a> {
         }
     }
 
+    pub fn as_elements(self) -> Box<[Value<'a>]> {
+        match self.data {
+            Data::List(list) => list.values(),
+            Data::Map(map) => map.keys(),
+            _ => panic!("expected list or map"),
+        }
+    }
+
+    pub fn as_map(self) -> GcMap<'a> {
+        match self.data {
+            Data::Map(map) => map,
+            _ => panic!("expected map"),
+        }
+    }
+
     pub fn as_method(self) -> GcMethod<'a> {
         match self.data {
             Data::Method(method) => method,
@@ -96,6 +125,13 @@ impl<'a> Value<'a> {
         }
     }
 
+    pub fn as_module(self) -> GcModule<'a> {
+        match self.data {
+            Data::Module(module) => module,
+            _ => panic!("expected module"),
+        }
+    }
+
     pub fn as_object(self) -> GcObject<'a> {
         match self.data {
             Data::Object(object) => object,
@@ -103,6 +139,13 @@ impl<'a> Value<'a> {
         }
     }
 
+    pub fn as_range(self) -> GcRange {
+        match self.data {
+            Data::Range(range) => range,
+            _ => panic!("expected range"),
+        }
+    }
+
     pub fn as_ref(self) -> Ref<'a> {
         match self.data {
             Data::Ref(r#ref) => r#ref,
@@ -2,7 +2,7 @@ use crate::ast::Pos;
 use crate::runtime::data::{Ref, GcClass};
 use crate::runtime::flow::Res;
 use crate::runtime::value::Value;
-use crate::runtime::gc::GcTrace;
+use crate::runtime::gc::{barrier, GcTrace};
 
 use super::eval::errors::error_type;
 
@@ -13,10 +13,13 @@ pub struct Variable<'a> {
 
 impl<'a> Variable<'a> {
     pub fn value(r#type: GcClass<'a>, value: Value<'a>) -> Self {
+        barrier(r#type);
+        barrier(value);
         Self { r#type, content: Some(value) }
     }
 
     pub fn undefined(r#type: GcClass<'a>) -> Self {
+        barrier(r#type);
         Self { r#type, content: None }
     }
 
@@ -33,6 +36,7 @@ impl<'a> Variable<'a> {
             return error_type(pos, value, self.r#type)
         }
 
+        barrier(value);
         self.content = Some(value);
         Ok(())
     }
@@ -40,6 +44,7 @@ impl<'a> Variable<'a> {
 
 impl GcTrace for Variable<'_> {
     fn trace(&mut self) {
+        self.r#type.trace();
         match self.content {
             Some(mut value) => value.trace(),
             None => (),
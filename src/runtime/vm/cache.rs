@@ -0,0 +1,71 @@
+use crate::ast::Pos;
+use crate::runtime::{Engine, Value};
+use crate::runtime::data::{FunctionBody, GcClass, GcFunction};
+use crate::runtime::eval::errors::error_member;
+use crate::runtime::flow::{Res, ResValue};
+use crate::runtime::gc::GcRef;
+
+use std::cell::Cell;
+
+pub struct InlineCache {
+    entry: Cell<Option<Entry>>,
+}
+
+#[derive(Clone, Copy)]
+struct Entry {
+    class: GcRef<()>,
+    revision: usize,
+    collections: usize,
+    method: Option<GcRef<()>>,
+    primitive: bool,
+}
+
+impl InlineCache {
+    pub fn new() -> Self {
+        Self {
+            entry: Cell::new(None),
+        }
+    }
+
+    pub fn lookup<'a>(&self, engine: &Engine<'a>, class: GcClass<'a>, name: &str) -> Option<GcFunction<'a>> {
+        self.entry(engine, class, name).method.map(GcRef::cast)
+    }
+
+    pub fn primitive<'a>(&self, engine: &Engine<'a>, class: GcClass<'a>, name: &str) -> bool {
+        self.entry(engine, class, name).primitive
+    }
+
+    pub fn call<'a>(&self, engine: &mut Engine<'a>, pos: Pos, name: &str, args: &[Value<'a>]) -> ResValue<'a> {
+        self.method(engine, pos, args[0].class, name)?.call(engine, pos, args)
+    }
+
+    pub fn method<'a>(&self, engine: &Engine<'a>, pos: Pos, class: GcClass<'a>, name: &str) -> Res<GcFunction<'a>> {
+        match self.lookup(engine, class, name) {
+            Some(method) => Ok(method),
+            None => error_member(pos, class, name),
+        }
+    }
+
+    fn entry<'a>(&self, engine: &Engine<'a>, class: GcClass<'a>, name: &str) -> Entry {
+        if let Some(entry) = self.entry.get() {
+            if entry.class == class.anonymize() && entry.revision == class.revision() && entry.collections == engine.gc.stats().collections {
+                return entry;
+            }
+        }
+
+        let method = class.get_method(name).map(Value::as_function);
+        let primitive = method.is_some_and(|method| matches!(method.body, FunctionBody::Primitive(_)))
+            && [engine.env.int, engine.env.float, engine.env.bool].contains(&class);
+
+        let entry = Entry {
+            class: class.anonymize(),
+            revision: class.revision(),
+            collections: engine.gc.stats().collections,
+            method: method.map(GcRef::anonymize),
+            primitive,
+        };
+
+        self.entry.set(Some(entry));
+        entry
+    }
+}
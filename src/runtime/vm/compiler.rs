@@ -0,0 +1,408 @@
+use crate::ast::nodes::*;
+use crate::memory::Ref;
+use crate::runtime::eval::{binop_method, parse_int, preop_method, unescape};
+use crate::runtime::vm::Chunk;
+use crate::runtime::vm::cache::InlineCache;
+use crate::runtime::vm::instruction::{Instruction, ClassPrototype, Prototype, TryPrototype};
+use crate::runtime::vm::resolver::{Declarations, Resolver};
+
+use std::mem;
+
+pub struct Compiler {
+    code: Vec<Instruction>,
+    resolver: Resolver,
+}
+
+impl Compiler {
+    pub fn new() -> Self {
+        Self {
+            code: Vec::new(),
+            resolver: Resolver::new(),
+        }
+    }
+
+    pub fn finish(self) -> Chunk {
+        Chunk::new(self.code.into_boxed_slice(), Box::new([]))
+    }
+
+    fn nested(&mut self, slots: Box<[Box<str>]>, compile: impl FnOnce(&mut Self)) -> Chunk {
+        let code = mem::take(&mut self.code);
+        compile(self);
+        let code = mem::replace(&mut self.code, code);
+        Chunk::new(code.into_boxed_slice(), slots)
+    }
+
+    fn emit(&mut self, instruction: Instruction) -> usize {
+        self.code.push(instruction);
+        self.code.len() - 1
+    }
+
+    fn label(&self) -> usize {
+        self.code.len()
+    }
+
+    fn patch(&mut self, index: usize) {
+        let label = self.label();
+        match &mut self.code[index] {
+            Instruction::Jump(target)
+            | Instruction::JumpIfFalse(target)
+            | Instruction::JumpIfTrue(target)
+            | Instruction::Short(_, target)
+            | Instruction::Next(target)
+            | Instruction::EnterLoop(_, target) => *target = label,
+            _ => panic!(),
+        }
+    }
+}
+
+impl Compiler {
+    pub fn program(&mut self, program: &AProgram) {
+        for stmt in program.stmts.iter() {
+            self.stmt(stmt);
+        }
+
+        self.emit(Instruction::Void);
+    }
+
+    pub fn block(&mut self, block: &ABlock) {
+        let scoped = self.push_scope(Declarations::block(block));
+        for stmt in block.stmts.iter() {
+            self.stmt(stmt);
+        }
+
+        match block.expr.as_ref() {
+            Some(expr) => self.read(expr),
+            None => { self.emit(Instruction::Void); },
+        }
+
+        self.pop_scope(scoped);
+    }
+
+    fn push_scope(&mut self, declarations: Declarations) -> bool {
+        if declarations.is_empty() {
+            return false;
+        }
+
+        self.emit(Instruction::PushScope(declarations.slots.clone().into_boxed_slice()));
+        self.resolver.push(declarations);
+        true
+    }
+
+    fn pop_scope(&mut self, scoped: bool) {
+        if scoped {
+            self.emit(Instruction::PopScope);
+            self.resolver.pop();
+        }
+    }
+
+    fn stmt(&mut self, stmt: &AStmt) {
+        match stmt {
+            AStmt::Expr(expr) => {
+                self.expr(expr);
+                self.emit(Instruction::Pop);
+            },
+            AStmt::Def(def) => {
+                self.def(def);
+            },
+            AStmt::Import(_) => {
+                self.emit(Instruction::Stmt(Ref::new(stmt)));
+            },
+            AStmt::Export(export) => {
+                if let Some(def) = export.def.as_ref() {
+                    self.def(def);
+                }
+
+                self.emit(Instruction::Export(export.names.clone()));
+            },
+        }
+    }
+
+    fn def(&mut self, def: &ADef) {
+        match def {
+            ADef::Class(class) if class.generics.is_empty() => {
+                let slot = self.resolver.declare(&class.name);
+                let methods = class.members.iter()
+                    .filter_map(|member| match member {
+                        AMember::Method(method) => Some(self.prototype(method)),
+                        AMember::Attribute(_) => None,
+                    })
+                    .collect();
+
+                self.emit(Instruction::Class(Box::new(ClassPrototype { node: Ref::new(class), methods }), slot));
+            },
+            ADef::Function(function) if function.generics.is_empty() => {
+                let slot = self.resolver.declare(&function.name);
+                let prototype = self.prototype(function);
+                self.emit(Instruction::Define(Box::new(prototype), slot));
+            },
+            _ => {
+                self.emit(Instruction::Generic(Ref::new(def)));
+            },
+        }
+    }
+
+    pub fn expr(&mut self, expr: &AExpr) {
+        match expr {
+            AExpr::Void(_) => {
+                self.emit(Instruction::Void);
+            },
+            AExpr::Bool(node) => {
+                self.emit(Instruction::Bool(node.bool));
+            },
+            AExpr::Int(node) => {
+                self.emit(match parse_int(&node.literal) {
+                    Ok(int) => Instruction::Int(int),
+                    Err(bigint) => Instruction::BigInt(bigint),
+                });
+            },
+            AExpr::Float(node) => {
+                self.emit(Instruction::Float(node.literal.replace("_", "").parse::<f64>().unwrap()));
+            },
+            AExpr::String(node) => {
+                self.emit(Instruction::String(Box::from(unescape(&node.literal))));
+            },
+            AExpr::Interpolation(node) => {
+                for expr in node.exprs.iter() {
+                    self.read(expr);
+                    self.emit(Instruction::Str(expr.pos(), InlineCache::new()));
+                }
+
+                let literals = node.literals.iter()
+                    .map(|literal| Box::from(unescape(literal)))
+                    .collect();
+
+                self.emit(Instruction::Interpolate(literals));
+            },
+            AExpr::Ident(node) => {
+                self.emit(match self.resolver.resolve(&node.ident) {
+                    Some((depth, index)) => Instruction::LoadSlot(depth, index, node.ident.clone(), node.pos),
+                    None => Instruction::Load(node.ident.clone(), node.pos),
+                });
+            },
+            AExpr::Var(node) => {
+                let instruction = match self.resolver.declare(&node.ident) {
+                    Some(index) => Instruction::DeclareSlot(index, Ref::new(node)),
+                    None => Instruction::Declare(Ref::new(node)),
+                };
+
+                self.emit(instruction);
+            },
+            AExpr::Chain(node) => {
+                self.read(&node.expr);
+                self.emit(Instruction::Chain(node.member.clone(), node.pos, InlineCache::new()));
+            },
+            AExpr::Apply(node) => {
+                self.read(&node.expr);
+                for arg in node.args.iter() {
+                    self.read(arg);
+                }
+
+                self.emit(Instruction::Apply(node.args.len(), node.pos));
+            },
+            AExpr::Call(node) => {
+                self.read(&node.expr);
+                for arg in node.args.iter() {
+                    self.read(arg);
+                }
+
+                self.emit(Instruction::Call(node.args.len(), node.pos, InlineCache::new()));
+            },
+            AExpr::Continue(node) => {
+                self.optional(&node.expr);
+                self.emit(Instruction::Continue(node.expr.is_some(), node.pos));
+            },
+            AExpr::Break(node) => {
+                self.optional(&node.expr);
+                self.emit(Instruction::Break(node.expr.is_some(), node.pos));
+            },
+            AExpr::Return(node) => {
+                self.optional(&node.expr);
+                self.emit(Instruction::Return(node.expr.is_some(), node.pos));
+            },
+            AExpr::Throw(node) => {
+                self.read(&node.expr);
+                self.emit(Instruction::Throw(node.pos));
+            },
+            AExpr::Preop(node) => {
+                self.read(&node.expr);
+                self.emit(Instruction::Unary(preop_method(&node.op), node.pos, InlineCache::new()));
+            },
+            AExpr::Binop(node) => {
+                self.read(&node.left);
+                self.read(&node.right);
+                self.emit(Instruction::Binary(binop_method(&node.op), node.pos, InlineCache::new()));
+            },
+            AExpr::Or(node) => {
+                self.read_bool(&node.left);
+                let short = self.emit(Instruction::JumpIfTrue(0));
+                self.read_bool(&node.right);
+                let end = self.emit(Instruction::Jump(0));
+                self.patch(short);
+                self.emit(Instruction::Bool(true));
+                self.patch(end);
+            },
+            AExpr::And(node) => {
+                self.read_bool(&node.left);
+                let short = self.emit(Instruction::JumpIfFalse(0));
+                self.read_bool(&node.right);
+                let end = self.emit(Instruction::Jump(0));
+                self.patch(short);
+                self.emit(Instruction::Bool(false));
+                self.patch(end);
+            },
+            AExpr::Block(node) => {
+                self.block(node);
+            },
+            AExpr::If(node) => {
+                self.read_bool(&node.cond);
+                let r#else = self.emit(Instruction::JumpIfFalse(0));
+                self.block(&node.then);
+                let end = self.emit(Instruction::Jump(0));
+                self.patch(r#else);
+                match node.r#else.as_ref() {
+                    Some(block) => self.block(block),
+                    None => { self.emit(Instruction::Void); },
+                }
+
+                self.patch(end);
+            },
+            AExpr::Loop(node) => {
+                let enter = self.emit(Instruction::EnterLoop(self.label() + 1, 0));
+                self.block(&node.body);
+                self.emit(Instruction::Collect);
+                self.emit(Instruction::Jump(enter + 1));
+                self.patch(enter);
+                self.emit(Instruction::ExitLoop);
+            },
+            AExpr::While(node) => {
+                let enter = self.emit(Instruction::EnterLoop(self.label() + 1, 0));
+                self.read_bool(&node.cond);
+                let exit = self.emit(Instruction::JumpIfFalse(0));
+                self.block(&node.body);
+                self.emit(Instruction::Collect);
+                self.emit(Instruction::Jump(enter + 1));
+                self.patch(enter);
+                self.patch(exit);
+                self.emit(Instruction::ExitLoop);
+            },
+            AExpr::For(node) => {
+                let scoped = self.push_scope(Declarations::expr(&node.list));
+                self.read(&node.list);
+                self.emit(Instruction::Iter(node.list.pos()));
+                let enter = self.emit(Instruction::EnterLoop(self.label() + 1, 0));
+                let next = self.emit(Instruction::Next(0));
+                let element: Box<[Box<str>]> = Box::new([node.element.clone()]);
+                self.emit(Instruction::PushScope(element.clone()));
+                self.resolver.push_visible(&element, false);
+                self.emit(Instruction::StoreSlot(0));
+                self.block(&node.body);
+                self.resolver.pop();
+                self.emit(Instruction::PopScope);
+                self.emit(Instruction::Collect);
+                self.emit(Instruction::Jump(next));
+                self.patch(enter);
+                self.patch(next);
+                self.emit(Instruction::ExitLoop);
+                self.emit(Instruction::Nip);
+                self.emit(Instruction::Nip);
+                self.pop_scope(scoped);
+            },
+            AExpr::Try(node) => {
+                let body = self.nested(Box::new([]), |compiler| compiler.block(&node.body));
+                let catch = node.catch.as_ref().map(|catch| {
+                    let slots: Box<[Box<str>]> = Box::new([catch.name.clone()]);
+                    self.resolver.push_visible(&slots, false);
+                    let chunk = self.nested(slots, |compiler| compiler.block(&catch.body));
+                    self.resolver.pop();
+                    chunk
+                });
+
+                let finally = node.finally.as_ref().map(|finally| self.nested(Box::new([]), |compiler| compiler.block(finally)));
+                self.emit(Instruction::Try(Box::new(TryPrototype { body, catch, finally })));
+            },
+            AExpr::Assign(node) => {
+                self.assign(node);
+            },
+            AExpr::Function(node) => {
+                let prototype = self.prototype(node);
+                self.emit(Instruction::Function(Box::new(prototype)));
+            },
+        }
+    }
+
+    fn assign(&mut self, node: &AExprAssign) {
+        let Some(op) = node.op.as_ref() else {
+            self.read(&node.right);
+            self.expr(&node.left);
+            self.emit(Instruction::CheckRef(node.left.pos()));
+            self.emit(Instruction::Assign(node.pos));
+            return;
+        };
+
+        self.expr(&node.left);
+        self.emit(Instruction::CheckRef(node.left.pos()));
+        self.emit(Instruction::Dup);
+        self.emit(Instruction::Read(node.left.pos()));
+        match op.as_ref() {
+            "&&" | "||" => {
+                self.emit(Instruction::CheckBool(node.left.pos()));
+                let short = self.emit(Instruction::Short(op.as_ref() == "||", 0));
+                self.read_bool(&node.right);
+                self.emit(Instruction::Swap);
+                self.emit(Instruction::Assign(node.pos));
+                let end = self.emit(Instruction::Jump(0));
+                self.patch(short);
+                self.emit(Instruction::Nip);
+                self.patch(end);
+            },
+            _ => {
+                let name = binop_method(op);
+                let inplace = Box::from(format!("__i{}", &name[2..]));
+                self.read(&node.right);
+                self.emit(Instruction::Compound(name, inplace, node.pos, InlineCache::new(), InlineCache::new()));
+            },
+        }
+    }
+
+    fn read(&mut self, expr: &AExpr) {
+        match expr {
+            AExpr::Ident(node) => {
+                self.emit(match self.resolver.resolve(&node.ident) {
+                    Some((depth, index)) => Instruction::LoadSlotValue(depth, index, node.ident.clone(), node.pos),
+                    None => Instruction::LoadValue(node.ident.clone(), node.pos),
+                });
+            },
+            AExpr::Void(_) | AExpr::Bool(_) | AExpr::Int(_) | AExpr::Float(_) | AExpr::String(_) | AExpr::Interpolation(_)
+            | AExpr::Or(_) | AExpr::And(_) | AExpr::Assign(_) | AExpr::Function(_) => {
+                self.expr(expr);
+            },
+            _ => {
+                self.expr(expr);
+                self.emit(Instruction::Read(expr.pos()));
+            },
+        }
+    }
+
+    fn read_bool(&mut self, expr: &AExpr) {
+        self.read(expr);
+        self.emit(Instruction::CheckBool(expr.pos()));
+    }
+
+    fn prototype(&mut self, function: &AFunction) -> Prototype {
+        let slots = function.params.iter()
+            .chain(function.rest.iter())
+            .map(|param| param.name.clone())
+            .collect::<Box<[_]>>();
+
+        self.resolver.push_visible(&slots, true);
+        let chunk = self.nested(slots, |compiler| compiler.block(&function.body));
+        self.resolver.pop();
+        Prototype { node: Ref::new(function), chunk }
+    }
+
+    fn optional(&mut self, expr: &Option<Box<AExpr>>) {
+        if let Some(expr) = expr.as_ref() {
+            self.read(expr);
+        }
+    }
+}
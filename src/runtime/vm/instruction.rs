@@ -0,0 +1,76 @@
+use crate::ast::Pos;
+use crate::ast::nodes::{AClass, ADef, AExprVar, AFunction, AStmt};
+use crate::memory::Ref;
+use crate::runtime::data::BigInt;
+use crate::runtime::vm::Chunk;
+use crate::runtime::vm::cache::InlineCache;
+
+pub enum Instruction {
+    Void,
+    Bool(bool),
+    Int(i64),
+    BigInt(BigInt),
+    Float(f64),
+    String(Box<str>),
+    Interpolate(Box<[Box<str>]>),
+    Str(Pos, InlineCache),
+    Load(Box<str>, Pos),
+    LoadValue(Box<str>, Pos),
+    LoadSlot(usize, usize, Box<str>, Pos),
+    LoadSlotValue(usize, usize, Box<str>, Pos),
+    Declare(Ref<AExprVar>),
+    DeclareSlot(usize, Ref<AExprVar>),
+    StoreSlot(usize),
+    Read(Pos),
+    CheckBool(Pos),
+    CheckRef(Pos),
+    Chain(Box<str>, Pos, InlineCache),
+    Apply(usize, Pos),
+    Call(usize, Pos, InlineCache),
+    Unary(&'static str, Pos, InlineCache),
+    Binary(&'static str, Pos, InlineCache),
+    Assign(Pos),
+    Compound(&'static str, Box<str>, Pos, InlineCache, InlineCache),
+    Jump(usize),
+    JumpIfFalse(usize),
+    JumpIfTrue(usize),
+    Short(bool, usize),
+    Pop,
+    Dup,
+    Nip,
+    Swap,
+    PushScope(Box<[Box<str>]>),
+    PopScope,
+    Iter(Pos),
+    Next(usize),
+    EnterLoop(usize, usize),
+    ExitLoop,
+    Collect,
+    Continue(bool, Pos),
+    Break(bool, Pos),
+    Return(bool, Pos),
+    Throw(Pos),
+    Try(Box<TryPrototype>),
+    Function(Box<Prototype>),
+    Define(Box<Prototype>, Option<usize>),
+    Class(Box<ClassPrototype>, Option<usize>),
+    Export(Box<[Box<str>]>),
+    Generic(Ref<ADef>),
+    Stmt(Ref<AStmt>),
+}
+
+pub struct Prototype {
+    pub node: Ref<AFunction>,
+    pub chunk: Chunk,
+}
+
+pub struct ClassPrototype {
+    pub node: Ref<AClass>,
+    pub methods: Box<[Prototype]>,
+}
+
+pub struct TryPrototype {
+    pub body: Chunk,
+    pub catch: Option<Chunk>,
+    pub finally: Option<Chunk>,
+}
@@ -0,0 +1,502 @@
+//! Bytecode backend, selected with `--backend=vm`.
+//!
+//! Programs are compiled to a compact stack bytecode that shares its values,
+//! scopes and garbage collector with the tree-walking evaluator. While
+//! compiling, local variables are resolved to slots in their scope, so that
+//! only global names and names that cannot be resolved ahead of time are
+//! looked up by name at runtime. Operators, member accesses and calls cache
+//! the method they resolve for the class of their receiver, and arithmetic and
+//! comparisons on `Int`, `Float` and `Bool` are computed directly as long as
+//! these classes still use their builtin methods.
+
+mod cache;
+mod compiler;
+mod instruction;
+mod operator;
+mod resolver;
+
+use crate::parser::Ast;
+use crate::runtime::{Engine, Value};
+use crate::runtime::data::Data;
+use crate::runtime::eval::{make_class_methods, make_function_chunk, read_type_any};
+use crate::runtime::eval::errors::error_type;
+use crate::runtime::flow::{Flow, Jump, JumpKind, Res, ResFlow};
+use crate::runtime::gc::GcTrace;
+use crate::runtime::scope::GcScope;
+use compiler::Compiler;
+use instruction::Instruction;
+
+use std::iter::zip;
+
+pub struct Chunk {
+    code: Box<[Instruction]>,
+    slots: Box<[Box<str>]>,
+}
+
+impl Chunk {
+    pub fn new(code: Box<[Instruction]>, slots: Box<[Box<str>]>) -> Self {
+        Self { code, slots }
+    }
+
+    pub fn slots(&self) -> &[Box<str>] {
+        &self.slots
+    }
+
+    pub fn compile(ast: &Ast) -> Self {
+        let mut compiler = Compiler::new();
+        match ast {
+            Ast::Program(program) => compiler.program(program),
+            Ast::Expression(expr) => compiler.expr(expr),
+        }
+
+        compiler.finish()
+    }
+
+    pub fn run<'a>(&self, engine: &mut Engine<'a>) -> ResFlow<'a> {
+        let mut vm = Vm::new();
+        engine.register(&mut vm);
+        let flow = vm.run(self, engine);
+        engine.unregister();
+        if let Ok(Flow::None(value) | Flow::Jump(Jump { value: Some(value), .. })) = flow.as_ref() {
+            engine.root(*value);
+        }
+
+        flow
+    }
+}
+
+struct Loop<'a> {
+    r#continue: usize,
+    r#break: usize,
+    height: usize,
+    scopes: usize,
+    value: Value<'a>,
+}
+
+struct Vm<'a> {
+    stack: Vec<Value<'a>>,
+    scopes: Vec<GcScope<'a>>,
+    loops: Vec<Loop<'a>>,
+}
+
+impl<'a> Vm<'a> {
+    fn new() -> Self {
+        Self {
+            stack: Vec::new(),
+            scopes: Vec::new(),
+            loops: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, value: Value<'a>) {
+        self.stack.push(value);
+    }
+
+    fn pop(&mut self) -> Value<'a> {
+        self.stack.pop().unwrap()
+    }
+
+    fn top(&mut self) -> &mut Value<'a> {
+        self.stack.last_mut().unwrap()
+    }
+
+    fn pop_many(&mut self, count: usize) -> Vec<Value<'a>> {
+        self.stack.split_off(self.stack.len() - count)
+    }
+
+    fn peek(&self, depth: usize) -> Value<'a> {
+        self.stack[self.stack.len() - depth - 1]
+    }
+
+    fn drop_many(&mut self, count: usize) {
+        self.stack.truncate(self.stack.len() - count);
+    }
+
+    fn run(&mut self, chunk: &Chunk, engine: &mut Engine<'a>) -> ResFlow<'a> {
+        let roots = engine.roots();
+        let mut pc = 0;
+        while let Some(instruction) = chunk.code.get(pc) {
+            let current = pc;
+            pc += 1;
+            let jump = match self.step(instruction, &mut pc, engine)? {
+                Some(jump) => jump,
+                None => {
+                    if pc <= current {
+                        engine.release(roots);
+                        engine.collect();
+                    }
+
+                    continue;
+                },
+            };
+
+            match self.unwind(engine, jump) {
+                Ok(target) => {
+                    pc = target;
+                    if pc <= current {
+                        engine.release(roots);
+                        engine.collect();
+                    }
+                },
+                Err(jump) => {
+                    if let Some(scope) = self.scopes.first() {
+                        engine.scope = *scope;
+                    }
+
+                    return Ok(Flow::Jump(jump));
+                },
+            }
+        }
+
+        Flow::none(self.pop())
+    }
+
+    fn unwind(&mut self, engine: &mut Engine<'a>, jump: Jump<'a>) -> Result<usize, Jump<'a>> {
+        if let JumpKind::Return = jump.jump {
+            return Err(jump);
+        }
+
+        let Some(r#loop) = self.loops.last_mut() else {
+            return Err(jump);
+        };
+
+        self.stack.truncate(r#loop.height);
+        while self.scopes.len() > r#loop.scopes {
+            engine.scope = self.scopes.pop().unwrap();
+        }
+
+        if let Some(value) = jump.value {
+            r#loop.value.as_list().append(engine.env.any, value);
+        }
+
+        Ok(match jump.jump {
+            JumpKind::Continue => r#loop.r#continue,
+            _ => r#loop.r#break,
+        })
+    }
+
+    fn define(&mut self, engine: &mut Engine<'a>, name: &str, slot: Option<usize>, value: Value<'a>) {
+        match slot {
+            Some(index) => engine.scope.set_slot(index, value.class, value),
+            None => engine.write_value(name, value),
+        }
+    }
+
+    fn step(&mut self, instruction: &Instruction, pc: &mut usize, engine: &mut Engine<'a>) -> Res<Option<Jump<'a>>> {
+        match instruction {
+            Instruction::Void => {
+                let value = engine.new_void();
+                self.push(value);
+            },
+            Instruction::Bool(bool) => {
+                let value = engine.new_bool(*bool);
+                self.push(value);
+            },
+            Instruction::Int(int) => {
+                let value = engine.new_int(*int);
+                self.push(value);
+            },
+            Instruction::BigInt(bigint) => {
+                let value = engine.new_bigint(bigint.clone());
+                self.push(value);
+            },
+            Instruction::Float(float) => {
+                let value = engine.new_float(*float);
+                self.push(value);
+            },
+            Instruction::String(string) => {
+                let value = engine.new_string(string);
+                self.push(value);
+            },
+            Instruction::Interpolate(literals) => {
+                let parts = self.pop_many(literals.len() - 1);
+                let mut string = literals[0].to_string();
+                for (part, literal) in zip(parts, literals[1 ..].iter()) {
+                    string.push_str(part.as_string().as_ref());
+                    string.push_str(literal);
+                }
+
+                let value = engine.new_string(&string);
+                self.push(value);
+            },
+            Instruction::Str(pos, cache) => {
+                let value = self.peek(0);
+                let value = cache.call(engine, *pos, "__str__", &[value])?;
+                *self.top() = value;
+            },
+            Instruction::Load(name, pos) => {
+                let r#ref = engine.read(*pos, name)?;
+                let value = engine.new_ref(r#ref);
+                self.push(value);
+            },
+            Instruction::LoadValue(name, pos) => {
+                let value = engine.read(*pos, name)?.read(*pos)?;
+                self.push(value);
+            },
+            Instruction::LoadSlot(depth, index, name, pos) => {
+                let r#ref = engine.read_slot(*pos, name, *depth, *index)?;
+                let value = engine.new_ref(r#ref);
+                self.push(value);
+            },
+            Instruction::LoadSlotValue(depth, index, name, pos) => {
+                let value = engine.read_slot(*pos, name, *depth, *index)?.read(*pos)?;
+                self.push(value);
+            },
+            Instruction::Declare(var) => {
+                let r#type = read_type_any(&var.r#type, engine)?;
+                engine.declare(&var.ident, r#type);
+                let r#ref = engine.read(var.pos, &var.ident)?;
+                let value = engine.new_ref(r#ref);
+                self.push(value);
+            },
+            Instruction::DeclareSlot(index, var) => {
+                let r#type = read_type_any(&var.r#type, engine)?;
+                engine.scope.declare_slot(*index, r#type);
+                let r#ref = engine.read_slot(var.pos, &var.ident, 0, *index)?;
+                let value = engine.new_ref(r#ref);
+                self.push(value);
+            },
+            Instruction::StoreSlot(index) => {
+                let value = self.pop();
+                engine.scope.set_slot(*index, value.class, value);
+            },
+            Instruction::Read(pos) => {
+                let value = self.top().read(*pos)?;
+                *self.top() = value;
+            },
+            Instruction::CheckBool(pos) => {
+                self.top().isa_type(*pos, engine.env.bool)?;
+            },
+            Instruction::CheckRef(pos) => {
+                self.top().isa_type(*pos, engine.env.r#ref)?;
+            },
+            Instruction::Chain(member, pos, cache) => {
+                let value = self.peek(0);
+                let attr = engine.new_string(member);
+                let value = cache.call(engine, *pos, "__cn__", &[value, attr])?;
+                *self.top() = value;
+            },
+            Instruction::Apply(count, pos) => {
+                let args = self.pop_many(*count).into_iter()
+                    .map(Value::as_class)
+                    .collect();
+
+                let generic = self.pop().as_generic();
+                let value = engine.get_generic(*pos, generic, args)?;
+                self.push(value);
+            },
+            Instruction::Call(count, pos, cache) => {
+                let receiver = self.peek(*count);
+                let args = engine.new_list(&self.stack[self.stack.len() - *count ..]);
+                let value = cache.call(engine, *pos, "__cl__", &[receiver, args])?;
+                self.drop_many(*count);
+                *self.top() = value;
+            },
+            Instruction::Unary(name, pos, cache) => {
+                let value = self.peek(0);
+                let value = operator::unary(engine, cache, *pos, name, value)?;
+                *self.top() = value;
+            },
+            Instruction::Binary(name, pos, cache) => {
+                let right = self.peek(0);
+                let left = self.peek(1);
+                let value = operator::binary(engine, cache, *pos, name, left, right)?;
+                self.drop_many(1);
+                *self.top() = value;
+            },
+            Instruction::Assign(pos) => {
+                let mut r#ref = self.pop().as_ref();
+                let value = self.pop();
+                r#ref.write(*pos, value)?;
+                self.push(value);
+            },
+            Instruction::Compound(name, inplace, pos, cache, inplace_cache) => {
+                let right = self.peek(0);
+                let left = self.peek(1);
+                let mut r#ref = self.peek(2).as_ref();
+                let value = match inplace_cache.lookup(engine, left.class, inplace) {
+                    Some(method) => method.call(engine, *pos, &[left, right])?,
+                    None => operator::binary(engine, cache, *pos, name, left, right)?,
+                };
+
+                r#ref.write(*pos, value)?;
+                value.store();
+                self.drop_many(2);
+                *self.top() = value;
+            },
+            Instruction::Jump(target) => {
+                *pc = *target;
+            },
+            Instruction::JumpIfFalse(target) => {
+                if !self.pop().as_bool() {
+                    *pc = *target;
+                }
+            },
+            Instruction::JumpIfTrue(target) => {
+                if self.pop().as_bool() {
+                    *pc = *target;
+                }
+            },
+            Instruction::Short(bool, target) => {
+                if self.top().as_bool() == *bool {
+                    *pc = *target;
+                } else {
+                    self.pop();
+                }
+            },
+            Instruction::Pop => {
+                self.pop();
+            },
+            Instruction::Dup => {
+                let value = *self.top();
+                self.push(value);
+            },
+            Instruction::Nip => {
+                let value = self.pop();
+                *self.top() = value;
+            },
+            Instruction::Swap => {
+                let length = self.stack.len();
+                self.stack.swap(length - 1, length - 2);
+            },
+            Instruction::PushScope(names) => {
+                self.scopes.push(engine.scope);
+                engine.scope = engine.new_scope_slots(engine.scope, names);
+            },
+            Instruction::PopScope => {
+                engine.scope = self.scopes.pop().unwrap();
+            },
+            Instruction::Iter(pos) => {
+                let iterable = self.pop();
+                let iterable = match iterable.data {
+                    Data::Range(_) => iterable,
+                    Data::List(_) | Data::Map(_) => engine.new_list(&iterable.as_elements()),
+                    _ => return error_type(*pos, iterable, engine.env.list_any),
+                };
+
+                let index = engine.new_int(0);
+                self.push(iterable);
+                self.push(index);
+            },
+            Instruction::Next(target) => {
+                let index = self.top().as_int() as usize;
+                let iterable = self.stack[self.stack.len() - 2];
+                let element = match iterable.data {
+                    Data::Range(range) if (index as i128) < range.len() => Some(engine.new_int(range.get(index as i128))),
+                    Data::List(list) if index < list.len() => Some(list.get(index)),
+                    _ => None,
+                };
+
+                match element {
+                    Some(element) => {
+                        *self.top() = engine.new_int(index as i64 + 1);
+                        self.push(element);
+                    },
+                    None => *pc = *target,
+                }
+            },
+            Instruction::EnterLoop(r#continue, r#break) => {
+                self.loops.push(Loop {
+                    r#continue: *r#continue,
+                    r#break: *r#break,
+                    height: self.stack.len(),
+                    scopes: self.scopes.len(),
+                    value: engine.new_list(&[]),
+                });
+            },
+            Instruction::ExitLoop => {
+                let r#loop = self.loops.pop().unwrap();
+                self.push(r#loop.value);
+            },
+            Instruction::Collect => {
+                let value = self.pop();
+                self.loops.last_mut().unwrap().value.as_list().append(engine.env.any, value);
+            },
+            Instruction::Continue(value, pos) => {
+                let value = value.then(|| self.pop());
+                return Jump::new(*pos, JumpKind::Continue, value);
+            },
+            Instruction::Break(value, pos) => {
+                let value = value.then(|| self.pop());
+                return Jump::new(*pos, JumpKind::Break, value);
+            },
+            Instruction::Return(value, pos) => {
+                let value = value.then(|| self.pop());
+                return Jump::new(*pos, JumpKind::Return, value);
+            },
+            Instruction::Throw(pos) => {
+                let value = self.pop();
+                return engine.throw(*pos, value);
+            },
+            Instruction::Try(r#try) => {
+                let mut result = engine.guard(|engine| r#try.body.run(engine));
+                if let Some(catch) = r#try.catch.as_ref() {
+                    if let Err(error) = result {
+                        let exception = engine.catch(error)?;
+                        result = engine.guard(|engine| engine.with_scope(|engine| {
+                            engine.scope.init_slots(catch.slots());
+                            engine.scope.set_slot(0, exception.class, exception);
+                            catch.run(engine)
+                        }));
+                    }
+                }
+
+                if let Some(finally) = r#try.finally.as_ref() {
+                    if let Flow::Jump(jump) = engine.with_thrown(|engine| finally.run(engine))? {
+                        return Jump::some(jump);
+                    }
+                }
+
+                match result? {
+                    Flow::None(value) => self.push(value),
+                    Flow::Jump(jump) => return Jump::some(jump),
+                }
+            },
+            Instruction::Function(prototype) => {
+                let value = make_function_chunk(&prototype.node, &prototype.chunk, engine)?;
+                self.push(value);
+            },
+            Instruction::Define(prototype, slot) => {
+                let value = make_function_chunk(&prototype.node, &prototype.chunk, engine)?;
+                self.define(engine, &prototype.node.name, *slot, value);
+            },
+            Instruction::Class(prototype, slot) => {
+                let methods = prototype.methods.iter()
+                    .map(|method| make_function_chunk(&method.node, &method.chunk, engine))
+                    .collect::<Res<Box<_>>>()?;
+
+                let value = make_class_methods(&prototype.node, &methods, engine)?;
+                self.define(engine, &prototype.node.name, *slot, value);
+            },
+            Instruction::Export(names) => {
+                for name in names.iter() {
+                    engine.export(name);
+                }
+            },
+            Instruction::Generic(def) => {
+                def.eval_def(engine)?;
+            },
+            Instruction::Stmt(stmt) => {
+                return stmt.eval_stmt(engine);
+            },
+        }
+
+        Jump::none()
+    }
+}
+
+impl GcTrace for Vm<'_> {
+    fn trace(&mut self) {
+        for value in self.stack.iter_mut() {
+            value.trace();
+        }
+
+        for scope in self.scopes.iter_mut() {
+            scope.trace();
+        }
+
+        for r#loop in self.loops.iter_mut() {
+            r#loop.value.trace();
+        }
+    }
+}
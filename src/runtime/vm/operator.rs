@@ -0,0 +1,87 @@
+use crate::ast::Pos;
+use crate::runtime::{Engine, Value};
+use crate::runtime::data::Data;
+use crate::runtime::flow::ResValue;
+use crate::runtime::vm::cache::InlineCache;
+
+pub fn unary<'a>(engine: &mut Engine<'a>, cache: &InlineCache, pos: Pos, name: &str, value: Value<'a>) -> ResValue<'a> {
+    if cache.primitive(engine, value.class, name) {
+        if let Some(value) = fast_unary(engine, name, value) {
+            return Ok(value);
+        }
+    }
+
+    cache.call(engine, pos, name, &[value])
+}
+
+pub fn binary<'a>(engine: &mut Engine<'a>, cache: &InlineCache, pos: Pos, name: &str, left: Value<'a>, right: Value<'a>) -> ResValue<'a> {
+    if cache.primitive(engine, left.class, name) {
+        if let Some(value) = fast_binary(engine, name, left, right) {
+            return Ok(value);
+        }
+    }
+
+    cache.call(engine, pos, name, &[left, right])
+}
+
+fn fast_unary<'a>(engine: &mut Engine<'a>, name: &str, value: Value<'a>) -> Option<Value<'a>> {
+    Some(match (value.data, name) {
+        (Data::Int(x),   "__pos__") => engine.new_int(x),
+        (Data::Int(x),   "__neg__") => engine.new_int(x.checked_neg()?),
+        (Data::Float(x), "__pos__") => engine.new_float(x),
+        (Data::Float(x), "__neg__") => engine.new_float(-x),
+        (Data::Bool(x),  "__not__") => engine.new_bool(!x),
+        _ => return None,
+    })
+}
+
+fn fast_binary<'a>(engine: &mut Engine<'a>, name: &str, left: Value<'a>, right: Value<'a>) -> Option<Value<'a>> {
+    match (left.data, right.data) {
+        (Data::Int(x),   Data::Int(y))   => int(engine, name, x, y),
+        (Data::Float(x), Data::Float(y)) => float(engine, name, x, y),
+        (Data::Bool(x),  Data::Bool(y))  => bool(engine, name, x, y),
+        _ => None,
+    }
+}
+
+fn int<'a>(engine: &mut Engine<'a>, name: &str, x: i64, y: i64) -> Option<Value<'a>> {
+    Some(match name {
+        "__add__" => engine.new_int(x.checked_add(y)?),
+        "__sub__" => engine.new_int(x.checked_sub(y)?),
+        "__mul__" => engine.new_int(x.checked_mul(y)?),
+        "__div__" => engine.new_int(x.checked_div(y)?),
+        "__rem__" => engine.new_int(x.checked_rem(y)?),
+        "__eq__"  => engine.new_bool(x == y),
+        "__ne__"  => engine.new_bool(x != y),
+        "__lt__"  => engine.new_bool(x < y),
+        "__gt__"  => engine.new_bool(x > y),
+        "__le__"  => engine.new_bool(x <= y),
+        "__ge__"  => engine.new_bool(x >= y),
+        _ => return None,
+    })
+}
+
+fn float<'a>(engine: &mut Engine<'a>, name: &str, x: f64, y: f64) -> Option<Value<'a>> {
+    Some(match name {
+        "__add__" => engine.new_float(x + y),
+        "__sub__" => engine.new_float(x - y),
+        "__mul__" => engine.new_float(x * y),
+        "__div__" => engine.new_float(x / y),
+        "__rem__" => engine.new_float(x % y),
+        "__eq__"  => engine.new_bool(x == y),
+        "__ne__"  => engine.new_bool(x != y),
+        "__lt__"  => engine.new_bool(x < y),
+        "__gt__"  => engine.new_bool(x > y || x.is_nan() || y.is_nan()),
+        "__le__"  => engine.new_bool(x <= y),
+        "__ge__"  => engine.new_bool(x >= y || x.is_nan() || y.is_nan()),
+        _ => return None,
+    })
+}
+
+fn bool<'a>(engine: &mut Engine<'a>, name: &str, x: bool, y: bool) -> Option<Value<'a>> {
+    Some(match name {
+        "__eq__" => engine.new_bool(x == y),
+        "__ne__" => engine.new_bool(x != y),
+        _ => return None,
+    })
+}
@@ -0,0 +1,207 @@
+use crate::ast::nodes::*;
+
+/// Resolves local variables to slots in their scope while a chunk is compiled.
+///
+/// Only the `vm` backend benefits from this: the tree-walking evaluator still
+/// allocates a `Scope` per block and looks every variable up by name.
+pub struct Resolver {
+    scopes: Vec<Scope>,
+}
+
+struct Scope {
+    names: Box<[Box<str>]>,
+    visible: Vec<bool>,
+    dynamic: Box<[Box<str>]>,
+    function: bool,
+}
+
+impl Resolver {
+    pub fn new() -> Self {
+        Self {
+            scopes: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, declarations: Declarations) {
+        let visible = vec![false; declarations.slots.len()];
+        self.scopes.push(Scope {
+            names: declarations.slots.into_boxed_slice(),
+            visible,
+            dynamic: declarations.dynamic.into_boxed_slice(),
+            function: false,
+        });
+    }
+
+    pub fn push_visible(&mut self, names: &[Box<str>], function: bool) {
+        self.scopes.push(Scope {
+            names: Box::from(names),
+            visible: vec![true; names.len()],
+            dynamic: Box::new([]),
+            function,
+        });
+    }
+
+    pub fn pop(&mut self) {
+        self.scopes.pop();
+    }
+
+    pub fn declare(&mut self, name: &str) -> Option<usize> {
+        let scope = self.scopes.last_mut()?;
+        let index = scope.names.iter().position(|slot| slot.as_ref() == name)?;
+        scope.visible[index] = true;
+        Some(index)
+    }
+
+    pub fn resolve(&self, name: &str) -> Option<(usize, usize)> {
+        let mut closure = false;
+        for (depth, scope) in self.scopes.iter().rev().enumerate() {
+            if let Some(index) = scope.names.iter().position(|slot| slot.as_ref() == name) {
+                if scope.visible[index] {
+                    return Some((depth, index));
+                }
+
+                if closure {
+                    return None;
+                }
+            }
+
+            if scope.dynamic.iter().any(|dynamic| dynamic.as_ref() == name) {
+                return None;
+            }
+
+            closure |= scope.function;
+        }
+
+        None
+    }
+}
+
+pub struct Declarations {
+    pub slots: Vec<Box<str>>,
+    pub dynamic: Vec<Box<str>>,
+}
+
+impl Declarations {
+    pub fn block(block: &ABlock) -> Self {
+        let mut declarations = Self::new();
+        for stmt in block.stmts.iter() {
+            declarations.stmt(stmt);
+        }
+
+        if let Some(expr) = block.expr.as_ref() {
+            declarations.visit(expr);
+        }
+
+        declarations
+    }
+
+    pub fn expr(expr: &AExpr) -> Self {
+        let mut declarations = Self::new();
+        declarations.visit(expr);
+        declarations
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty() && self.dynamic.is_empty()
+    }
+
+    fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            dynamic: Vec::new(),
+        }
+    }
+
+    fn slot(&mut self, name: &str) {
+        if !self.slots.iter().any(|slot| slot.as_ref() == name) {
+            self.slots.push(Box::from(name));
+        }
+    }
+
+    fn stmt(&mut self, stmt: &AStmt) {
+        match stmt {
+            AStmt::Expr(expr) => self.visit(expr),
+            AStmt::Def(def) => self.def(def),
+            AStmt::Import(import) => {
+                self.dynamic.extend(import.alias.iter().cloned());
+                self.dynamic.extend(import.names.iter().cloned());
+            },
+            AStmt::Export(export) => if let Some(def) = export.def.as_ref() {
+                self.def(def);
+            },
+        }
+    }
+
+    fn def(&mut self, def: &ADef) {
+        match def {
+            ADef::Class(class) if class.generics.is_empty() => self.slot(&class.name),
+            ADef::Function(function) if function.generics.is_empty() => self.slot(&function.name),
+            ADef::Class(class) => self.dynamic.push(class.name.clone()),
+            ADef::Function(function) => self.dynamic.push(function.name.clone()),
+        }
+    }
+
+    fn visit(&mut self, expr: &AExpr) {
+        match expr {
+            AExpr::Var(node) => {
+                self.slot(&node.ident);
+            },
+            AExpr::Interpolation(node) => {
+                for expr in node.exprs.iter() {
+                    self.visit(expr);
+                }
+            },
+            AExpr::Chain(node) => {
+                self.visit(&node.expr);
+            },
+            AExpr::Apply(node) => {
+                self.visit(&node.expr);
+                for arg in node.args.iter() {
+                    self.visit(arg);
+                }
+            },
+            AExpr::Call(node) => {
+                self.visit(&node.expr);
+                for arg in node.args.iter() {
+                    self.visit(arg);
+                }
+            },
+            AExpr::Continue(AExprContinue { expr, .. })
+            | AExpr::Break(AExprBreak { expr, .. })
+            | AExpr::Return(AExprReturn { expr, .. }) => {
+                if let Some(expr) = expr.as_ref() {
+                    self.visit(expr);
+                }
+            },
+            AExpr::Throw(node) => {
+                self.visit(&node.expr);
+            },
+            AExpr::Preop(node) => {
+                self.visit(&node.expr);
+            },
+            AExpr::Binop(node) => {
+                self.visit(&node.left);
+                self.visit(&node.right);
+            },
+            AExpr::Or(node) => {
+                self.visit(&node.left);
+                self.visit(&node.right);
+            },
+            AExpr::And(node) => {
+                self.visit(&node.left);
+                self.visit(&node.right);
+            },
+            AExpr::Assign(node) => {
+                self.visit(&node.left);
+                self.visit(&node.right);
+            },
+            AExpr::If(node) => {
+                self.visit(&node.cond);
+            },
+            AExpr::While(node) => {
+                self.visit(&node.cond);
+            },
+            _ => (),
+        }
+    }
+}
@@ -1,10 +1,12 @@
 use crate::parser;
-use crate::parser::Code;
-use crate::runtime::engine::{Engine, Io};
+use crate::repl;
+use crate::runtime::engine::{Backend, Engine, Io};
 
+use std::env;
 use std::ffi::OsStr;
 use std::fs;
 use std::io::empty;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::path::PathBuf;
 
 struct Test {
@@ -16,30 +18,42 @@ struct Test {
 
 #[test]
 fn test() {
+    let samples = PathBuf::from("./samples/");
     let mut paths = Vec::new();
-    fill_paths(PathBuf::from("./src/tests/samples/"), &mut paths);
+    fill_paths(samples.clone(), &mut paths);
+    let root = env::current_dir().unwrap();
     for path in paths {
-        let test = make_test(&path);
-        let grammar = parser::grammar();
-        let mut r#in  = empty();
-        let mut out = Vec::new();
-        let mut err  = Vec::new();
-        let io = Io::new(&mut r#in, &mut out, &mut err);
+        for (backend, incremental) in [(Backend::Ast, false), (Backend::Vm, false), (Backend::Ast, true), (Backend::Vm, true)] {
+            let test = make_test(&path);
+            env::set_current_dir(path.parent().unwrap()).unwrap();
+            let grammar = parser::grammar();
+            let mut r#in  = empty();
+            let mut out = Vec::new();
+            let mut err  = Vec::new();
+            let io = Io::new(&mut r#in, &mut out, &mut err);
 
-        {
-            let mut engine = Engine::new(io, &grammar);
-            let code = Code::new(engine.grammar, engine.grammar.program, Some(&test.name), test.code.clone().into_boxed_str());
-            engine.run(code);
-        }
+            let run = catch_unwind(AssertUnwindSafe(|| {
+                let mut engine = Engine::new(io, &grammar);
+                engine.backend = backend;
+                engine.gc.incremental = incremental;
+                engine.gc.verify = true;
+                engine.paths.push(root.join(&samples).join("lib"));
+                if let Some(code) = engine.parse(Some(&test.name), test.code.clone().into_boxed_str()) {
+                    engine.run(code);
+                }
+            }));
 
-        compare_results(test, out, err);
+            env::set_current_dir(&root).unwrap();
+            assert!(run.is_ok(), "\nTEST FAIL: file `{}` ({:?} backend, {} GC)\nINTERPRETER PANICKED", test.name, backend, gc_name(incremental));
+            compare_results(test, backend, incremental, out, err);
+        }
     }
 }
 
 fn fill_paths(path: PathBuf, paths: &mut Vec<PathBuf>) {
     if path.extension() == Some(OsStr::new("lif")) {
         paths.push(path);
-    } else if path.is_dir() {
+    } else if path.is_dir() && path.file_name() != Some(OsStr::new("lib")) {
         for dir in fs::read_dir(path).unwrap() {
             fill_paths(dir.unwrap().path(), paths);
         }
@@ -54,13 +68,37 @@ fn make_test(path: &PathBuf) -> Test {
     Test { name, code, out, err }
 }
 
-fn compare_results(test: Test, out: Vec<u8>, err: Vec<u8>) {
+fn compare_results(test: Test, backend: Backend, incremental: bool, out: Vec<u8>, err: Vec<u8>) {
     let out = clean_string(&String::from_utf8(out).unwrap());
     let err = clean_string(&String::from_utf8(err).unwrap());
-    assert_eq!(out, test.out, "\nTEST FAIL: file `{}`\nEXPECTED OUTPUT:\n{}FOUND OUTPUT:\n{}", test.name, test.out, out);
-    assert_eq!(err, test.err, "\nTEST FAIL: file `{}`\nEXPECTED ERROR:\n{}FOUND ERROR:\n{}", test.name, test.err, err);
+    let gc = gc_name(incremental);
+    assert_eq!(out, test.out, "\nTEST FAIL: file `{}` ({:?} backend, {} GC)\nEXPECTED OUTPUT:\n{}FOUND OUTPUT:\n{}", test.name, backend, gc, test.out, out);
+    assert_eq!(err, test.err, "\nTEST FAIL: file `{}` ({:?} backend, {} GC)\nEXPECTED ERROR:\n{}FOUND ERROR:\n{}", test.name, backend, gc, test.err, err);
+}
+
+fn gc_name(incremental: bool) -> &'static str {
+    if incremental { "incremental" } else { "stop-the-world" }
 }
 
 fn clean_string(input: &String) -> String {
     input.replace("\r\n", "\n")
 }
+
+#[test]
+fn repl() {
+    let grammar = parser::grammar();
+    let mut r#in = empty();
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    let io = Io::new(&mut r#in, &mut out, &mut err);
+    let mut input = "let x = 1;\nfunction f(a) {\n    return a + x;\n}\nprint(zz);\nf(2)\n".as_bytes();
+    let mut engine = Engine::new(io, &grammar);
+    engine.gc.verify = true;
+    repl::run(&mut engine, &mut input);
+    drop(engine);
+
+    let out = String::from_utf8(out).unwrap();
+    let err = String::from_utf8(err).unwrap();
+    assert_eq!(out, "> > ... ... > > 3\n> ");
+    assert!(err.contains("undeclared variable `zz`"), "\nREPL ERROR:\n{}", err);
+}